@@ -5,14 +5,16 @@ use async_std::io;
 use async_std::sync;
 use futures::executor::{block_on, LocalPool};
 use futures::task::LocalSpawnExt;
-use rohrpost::helpers::Or;
 use rohrpost::telegram_methods;
 use rohrpost::telegram_receiver;
 use rohrpost::telegram_sender;
 use rohrpost::telegram_types;
+use rohrpost::Dispatcher;
+use rohrpost::Error;
 use rohrpost::TelegramReceiver;
 use rohrpost::TelegramSender;
 use std::net::SocketAddr;
+use std::rc::Rc;
 
 use std::path::PathBuf;
 
@@ -64,9 +66,16 @@ fn main() {
     local_spawn
         .spawn_local(recv.run(local_spawn.clone()))
         .unwrap();
-    println!("Spawn echo_bot future");
+    println!("Spawn dispatcher future");
+    let dispatcher = Dispatcher::new(sender)
+        .on_message(echo)
+        .on_error(|update_id, err| println!("update {:?} failed: {}", update_id, err));
     local_spawn
-        .spawn_local(echo_bot(http_recv, sender))
+        .spawn_local(async {
+            if let Err(err) = dispatcher.run_webhook(http_recv).await {
+                println!("could not start the dispatcher: {}", err);
+            }
+        })
         .unwrap();
     local_pool.run();
 }
@@ -81,70 +90,28 @@ async fn send_stop_on_stdin_enter(sender: sync::Sender<()>) {
     sender.send(()).await;
 }
 
-async fn register_web_hook(sender: &TelegramSender, receiver: &TelegramReceiver) -> io::Result<()> {
+async fn register_web_hook(
+    sender: &TelegramSender,
+    receiver: &TelegramReceiver,
+) -> Result<(), Error> {
     sender.register_web_hook(receiver).await?;
     Ok(())
 }
 
-fn parse_into_telegram_update(bytes: Vec<u8>) -> Option<telegram_types::Update> {
-    let json = String::from_utf8(bytes);
-    match json {
-        Err(err) => {
-            println!("Could not parse body into utf-8 string");
-            println!("{}", err);
-            None
-        }
-        Ok(json) => {
-            let update = serde_json::from_str(&json);
-            match update {
-                Err(err) => {
-                    println!("Could not deserialize string into Update");
-                    println!("{}", err);
-                    None
-                }
-                Ok(update) => Some(update),
-            }
+async fn echo(sender: Rc<TelegramSender>, message: telegram_types::Message) -> Result<(), Error> {
+    match (&message.from, message.text) {
+        (Some(_), Some(text)) => {
+            println!("Building method");
+            let method = telegram_methods::sendMessageBuilder::default()
+                .chat_id(&message.chat)
+                .text(text)
+                .reply_to_message_id(message.message_id)
+                .build()
+                .unwrap();
+            sender.call(&method).await?;
+            println!("Finished echoing");
         }
+        _ => println!("Dont know whom or what to answer"),
     }
-}
-
-async fn echo_bot(http_recv: sync::Receiver<telegram_receiver::SendItem>, sender: TelegramSender) {
-    while let Some((http_msg, response_channel)) = http_recv.recv().await {
-        let body = http_msg.get_body();
-        let update = parse_into_telegram_update(body.clone());
-        match update {
-            Some(update) => {
-                if let Some(message) = update.message {
-                    if message.from.is_some() && message.text.is_some() {
-                        println!("Building method");
-                        let method = telegram_methods::sendMessageBuilder::default()
-                            .chat_id(Or::A(message.chat.id))
-                            .text(message.text.unwrap())
-                            .reply_to_message_id(message.message_id)
-                            .build()
-                            .unwrap();
-                        let result = sender.call(&method).await;
-                        if result.is_err() {
-                            println!("IO Error while sending method {}", result.err().unwrap());
-                        } else {
-                            let http_msg = result.unwrap();
-                            if http_msg.get_response().status().as_u16() != 200 {
-                                println!("{:#?}", http_msg);
-                            }
-                        }
-                    } else {
-                        println!("Dont know whom or what to answer");
-                    }
-                } else {
-                    println!("message is none");
-                }
-            }
-            None => {}
-        }
-        //regardless of what the update contained, tell the server we received it (so it doesnt repeat itself).
-        let response = rohrpost::http_stream::HttpMsg::new_respone(200);
-        response_channel.send(response).await;
-        println!("Finished echoing");
-    }
-    println!("Finished echo bot");
+    Ok(())
 }
@@ -0,0 +1,59 @@
+extern crate rohrpost;
+use structopt::StructOpt;
+
+use futures::executor::block_on;
+use rohrpost::telegram_methods;
+use rohrpost::telegram_poller;
+use rohrpost::telegram_sender;
+use rohrpost::telegram_types;
+use rohrpost::Dispatcher;
+use rohrpost::Error;
+use rohrpost::MessageKind;
+use rohrpost::TelegramSender;
+
+use std::path::PathBuf;
+use std::rc::Rc;
+
+#[derive(StructOpt, Debug)]
+#[structopt(name = "poll_bot")]
+struct Opt {
+    ///File that contains the api token
+    #[structopt(short, long, parse(from_os_str))]
+    token_file: PathBuf,
+    ///Long poll timeout in seconds
+    #[structopt(long, default_value = "30")]
+    timeout: i64,
+}
+
+fn main() {
+    let opt = Opt::from_args();
+    let token = std::fs::read_to_string(&opt.token_file)
+        .unwrap()
+        .trim()
+        .to_string();
+    let config = telegram_sender::Config::new(token);
+    let sender = TelegramSender::new(config);
+    let dispatcher = Dispatcher::new(sender)
+        .on_message(echo)
+        .on_error(|update_id, err| println!("update {:?} failed: {}", update_id, err));
+    let config = telegram_poller::Config::new(opt.timeout);
+    if let Err(err) = block_on(dispatcher.run_polling(config)) {
+        println!("could not start polling: {}", err);
+    }
+}
+
+async fn echo(sender: Rc<TelegramSender>, message: telegram_types::Message) -> Result<(), Error> {
+    let text = match message.kind() {
+        MessageKind::Text { text, .. } => String::from(text),
+        MessageKind::Sticker(sticker) => sticker.emoji.clone().unwrap_or_default(),
+        _ => return Ok(()),
+    };
+    let method = telegram_methods::sendMessageBuilder::default()
+        .chat_id(&message.chat)
+        .text(text)
+        .reply_to_message_id(message.message_id)
+        .build()
+        .unwrap();
+    sender.call(&method).await?;
+    Ok(())
+}
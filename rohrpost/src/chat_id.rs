@@ -0,0 +1,170 @@
+use crate::telegram_types::{Chat, Message};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+///Unique identifier of the target chat or username of the target channel (in the format @channelusername).
+/// Builders in telegram_methods accept everything that converts into it, for example an i64, a "@channelusername", a &Chat or a &Message.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChatId {
+    Id(i64),
+    ///Including the leading @
+    Username(String),
+}
+
+impl From<i64> for ChatId {
+    fn from(id: i64) -> Self {
+        ChatId::Id(id)
+    }
+}
+
+impl From<&Chat> for ChatId {
+    fn from(chat: &Chat) -> Self {
+        ChatId::Id(chat.id)
+    }
+}
+
+///The chat the message belongs to
+impl From<&Message> for ChatId {
+    fn from(message: &Message) -> Self {
+        ChatId::Id(message.chat.id)
+    }
+}
+
+///Numeric strings become an Id, everything else a Username.
+/// The builders in telegram_methods check the username when the method is built, see ChatId::check.
+impl From<&str> for ChatId {
+    fn from(value: &str) -> Self {
+        match value.parse() {
+            Ok(id) => ChatId::Id(id),
+            Err(_) => ChatId::Username(String::from(value)),
+        }
+    }
+}
+
+impl From<String> for ChatId {
+    fn from(value: String) -> Self {
+        match value.parse() {
+            Ok(id) => ChatId::Id(id),
+            Err(_) => ChatId::Username(value),
+        }
+    }
+}
+
+///Error for strings that are neither a numeric id nor a valid @channelusername
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidChatId(pub String);
+
+impl fmt::Display for InvalidChatId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} is neither a chat id nor a @channelusername",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidChatId {}
+
+///Usernames are 5-32 characters long, start with a letter and contain only letters, digits and underscores
+fn is_username(name: &str) -> bool {
+    (5..=32).contains(&name.len())
+        && name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl ChatId {
+    ///A @channelusername, checked to be a valid username
+    pub fn username(value: &str) -> Result<Self, InvalidChatId> {
+        match value.strip_prefix('@') {
+            Some(name) if is_username(name) => Ok(ChatId::Username(String::from(value))),
+            _ => Err(InvalidChatId(String::from(value))),
+        }
+    }
+
+    ///Fails for a Username that is not a valid @channelusername
+    pub fn check(&self) -> Result<(), InvalidChatId> {
+        match self {
+            ChatId::Id(_) => Ok(()),
+            ChatId::Username(name) => ChatId::username(name).map(|_| ()),
+        }
+    }
+}
+
+///Parses a numeric id or a valid @channelusername
+impl FromStr for ChatId {
+    type Err = InvalidChatId;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.parse() {
+            Ok(id) => Ok(ChatId::Id(id)),
+            Err(_) => ChatId::username(value),
+        }
+    }
+}
+
+impl fmt::Display for ChatId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChatId::Id(id) => write!(f, "{}", id),
+            ChatId::Username(name) => f.write_str(name),
+        }
+    }
+}
+
+impl Serialize for ChatId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ChatId::Id(id) => serializer.serialize_i64(*id),
+            ChatId::Username(name) => serializer.serialize_str(name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ids_and_usernames() {
+        assert_eq!("-1001234".parse(), Ok(ChatId::Id(-1001234)));
+        assert_eq!(
+            ChatId::username("@rust_news"),
+            Ok(ChatId::Username(String::from("@rust_news")))
+        );
+        assert!("rust_news".parse::<ChatId>().is_err());
+        assert!("@abc".parse::<ChatId>().is_err());
+        assert!("@rust-news".parse::<ChatId>().is_err());
+    }
+
+    #[test]
+    fn builders_accept_strings() {
+        assert_eq!(ChatId::from("-1001234"), ChatId::Id(-1001234));
+        assert_eq!(
+            ChatId::from(String::from("@rust_news")),
+            ChatId::Username(String::from("@rust_news"))
+        );
+        let method = crate::telegram_methods::sendMessageBuilder::default()
+            .chat_id("@rust_news")
+            .text(String::from("hi"))
+            .build()
+            .unwrap();
+        assert_eq!(method.chat_id, ChatId::Username(String::from("@rust_news")));
+        //invalid usernames are refused when the method is built
+        for chat_id in &["rust_news", "@a b"] {
+            let method = crate::telegram_methods::sendMessageBuilder::default()
+                .chat_id(*chat_id)
+                .text(String::from("hi"))
+                .build();
+            assert!(method.is_err());
+        }
+        let method = crate::telegram_methods::editMessageTextBuilder::default()
+            .chat_id("@x")
+            .text(String::from("hi"))
+            .build();
+        assert!(method.is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use crate::telegram_types::{Message, MessageEntity, MessageEntityType};
+use std::fmt;
+use std::str::FromStr;
+
+///A bot command at the start of a message, for example "/start@my_bot some arguments"
+#[derive(Debug, Clone, PartialEq)]
+pub struct Command {
+    ///Name of the command without the leading slash, "start" in the example
+    pub name: String,
+    ///The bot the command is addressed to, "my_bot" in the example
+    pub bot_username: Option<String>,
+    ///Everything after the command, "some arguments" in the example
+    pub args: String,
+}
+
+impl Command {
+    ///Extracts the command from the text of the message.
+    /// Only a bot_command entity at the very start of the text counts as command.
+    pub fn parse(message: &Message) -> Option<Self> {
+        let text = message.text.as_ref()?;
+        let entity =
+            message.entities.as_ref()?.iter().find(|entity| {
+                entity.type_ == MessageEntityType::BotCommand && entity.offset == 0
+            })?;
+        Command::from_entity(text, entity)
+    }
+    fn from_entity(text: &str, entity: &MessageEntity) -> Option<Self> {
+        let end = utf16_to_byte_offset(text, entity.offset + entity.length)?;
+        let command = text[..end].strip_prefix('/')?;
+        let (name, bot_username) = match command.find('@') {
+            Some(at) => (&command[..at], Some(String::from(&command[at + 1..]))),
+            None => (command, None),
+        };
+        Some(Command {
+            name: String::from(name),
+            bot_username,
+            args: String::from(text[end..].trim()),
+        })
+    }
+    ///False if the command names another bot, as it happens in groups with several bots.
+    pub fn is_addressed_to(&self, bot_username: &str) -> bool {
+        match &self.bot_username {
+            Some(name) => name.eq_ignore_ascii_case(bot_username),
+            None => true,
+        }
+    }
+    ///Parses the arguments, see FromArgs
+    pub fn parse_args<T>(&self) -> Result<T, ArgsError>
+    where
+        T: FromArgs,
+    {
+        T::from_args(&self.args)
+    }
+}
+
+///Entity offsets are counted in UTF-16 code units
+fn utf16_to_byte_offset(text: &str, offset: i64) -> Option<usize> {
+    let mut units = 0;
+    for (index, c) in text.char_indices() {
+        if units == offset {
+            return Some(index);
+        }
+        units += c.len_utf16() as i64;
+    }
+    if units == offset {
+        Some(text.len())
+    } else {
+        None
+    }
+}
+
+///Error returned when the arguments of a command do not fit the expected type
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgsError {
+    Count { expected: usize, found: usize },
+    Invalid { position: usize, value: String },
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgsError::Count { expected, found } => {
+                write!(f, "expected {} arguments but found {}", expected, found)
+            }
+            ArgsError::Invalid { position, value } => {
+                write!(f, "argument {} is invalid: {:?}", position + 1, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArgsError {}
+
+///Conversion from the arguments of a command.
+/// Implemented for String (the raw arguments), Vec<T> and tuples of up to four FromStr types,
+/// which take exactly that many whitespace separated arguments.
+pub trait FromArgs: Sized {
+    fn from_args(args: &str) -> Result<Self, ArgsError>;
+}
+
+fn parse_arg<T: FromStr>(position: usize, value: &str) -> Result<T, ArgsError> {
+    value.parse().map_err(|_| ArgsError::Invalid {
+        position,
+        value: String::from(value),
+    })
+}
+
+impl FromArgs for String {
+    fn from_args(args: &str) -> Result<Self, ArgsError> {
+        Ok(String::from(args))
+    }
+}
+
+impl<T: FromStr> FromArgs for Vec<T> {
+    fn from_args(args: &str) -> Result<Self, ArgsError> {
+        args.split_whitespace()
+            .enumerate()
+            .map(|(position, value)| parse_arg(position, value))
+            .collect()
+    }
+}
+
+macro_rules! tuple_from_args {
+    ($count:expr; $($name:ident $position:tt),*) => {
+        impl<$($name: FromStr),*> FromArgs for ($($name,)*) {
+            fn from_args(args: &str) -> Result<Self, ArgsError> {
+                let args: Vec<&str> = args.split_whitespace().collect();
+                if args.len() != $count {
+                    return Err(ArgsError::Count {
+                        expected: $count,
+                        found: args.len(),
+                    });
+                }
+                Ok(($(parse_arg::<$name>($position, args[$position])?,)*))
+            }
+        }
+    };
+}
+
+tuple_from_args!(0;);
+tuple_from_args!(1; A 0);
+tuple_from_args!(2; A 0, B 1);
+tuple_from_args!(3; A 0, B 1, C 2);
+tuple_from_args!(4; A 0, B 1, C 2, D 3);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(offset: i64, length: i64) -> MessageEntity {
+        MessageEntity {
+            type_: MessageEntityType::BotCommand,
+            offset,
+            length,
+            url: None,
+            user: None,
+        }
+    }
+
+    #[test]
+    fn command_with_bot_name_and_args() {
+        let text = "/remind@my_bot 15 green tea 🍵";
+        let command = Command::from_entity(text, &entity(0, 14)).unwrap();
+        assert_eq!(command.name, "remind");
+        assert_eq!(command.bot_username.as_deref(), Some("my_bot"));
+        assert!(command.is_addressed_to("My_Bot"));
+        assert!(!command.is_addressed_to("other_bot"));
+        assert_eq!(command.args, "15 green tea 🍵");
+        assert_eq!(
+            command.parse_args::<(u32, String)>(),
+            Err(ArgsError::Count {
+                expected: 2,
+                found: 4
+            })
+        );
+        let (minutes, rest) = Command {
+            args: String::from("15 tea"),
+            ..command
+        }
+        .parse_args::<(u32, String)>()
+        .unwrap();
+        assert_eq!((minutes, rest.as_str()), (15, "tea"));
+    }
+}
@@ -0,0 +1,49 @@
+use crate::telegram_types::{
+    Animation, Audio, Document, PhotoSize, Sticker, Video, VideoNote, Voice,
+};
+
+///A file in a message that can be downloaded with TelegramSender::download
+pub trait Downloadable {
+    fn file_id(&self) -> &str;
+    ///Size in bytes, if telegram sent it
+    fn file_size(&self) -> Option<i64>;
+}
+
+macro_rules! downloadable {
+    ($($type:ty),*) => {
+        $(
+            impl Downloadable for $type {
+                fn file_id(&self) -> &str {
+                    &self.file_id
+                }
+                fn file_size(&self) -> Option<i64> {
+                    self.file_size
+                }
+            }
+        )*
+    };
+}
+
+downloadable!(PhotoSize, Document, Voice, Sticker, Audio, Video, Animation, VideoNote);
+
+///A photo is sent in several sizes, this returns the one with the most pixels
+pub fn largest_photo(sizes: &[PhotoSize]) -> Option<&PhotoSize> {
+    sizes.iter().max_by_key(|size| size.width * size.height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn largest_photo_has_the_most_pixels() {
+        let sizes: Vec<PhotoSize> = serde_json::from_str(
+            r#"[{"file_id":"s","width":90,"height":60},{"file_id":"l","width":1280,"height":853,"file_size":9000},{"file_id":"m","width":320,"height":213}]"#,
+        )
+        .unwrap();
+        let largest = largest_photo(&sizes).unwrap();
+        assert_eq!(largest.file_id(), "l");
+        assert_eq!(largest.file_size(), Some(9000));
+        assert!(largest_photo(&[]).is_none());
+    }
+}
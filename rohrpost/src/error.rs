@@ -0,0 +1,84 @@
+use crate::http_stream;
+use std::fmt;
+use std::io;
+
+///Everything that can go wrong while calling a telegram method
+#[derive(Debug)]
+pub enum Error {
+    ///Transport error while connecting, writing or reading
+    IO(io::Error),
+    ///The http message we received could not be parsed
+    Parse(()),
+    ///The request could not be serialized or the response could not be deserialized
+    Json(serde_json::Error),
+    ///Telegram answered the request with ok: false
+    Api {
+        ///Error code of the response, its contents are subject to change in the future
+        error_code: i64,
+        ///Human-readable description of the error
+        description: String,
+        ///In case of exceeding flood control, the number of seconds left to wait before the request can be repeated
+        retry_after: Option<i64>,
+        ///The group has been migrated to a supergroup with the specified identifier
+        migrate_to_chat_id: Option<i64>,
+    },
+    ///Telegram Passport data could not be decrypted or failed verification
+    Passport(&'static str),
+    ///The file is larger than the configured download limit
+    FileTooLarge { size: u64, limit: u64 },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::IO(err) => write!(f, "IO error: {}", err),
+            Error::Parse(()) => write!(f, "could not parse http message"),
+            Error::Json(err) => write!(f, "JSON error: {}", err),
+            Error::Api {
+                error_code,
+                description,
+                ..
+            } => write!(f, "telegram api error {}: {}", error_code, description),
+            Error::Passport(reason) => write!(f, "passport error: {}", reason),
+            Error::FileTooLarge { size, limit } => write!(
+                f,
+                "file of {} bytes is larger than the download limit of {} bytes",
+                size, limit
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::IO(err) => Some(err),
+            Error::Json(err) => Some(err),
+            Error::Parse(())
+            | Error::Api { .. }
+            | Error::Passport(_)
+            | Error::FileTooLarge { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::IO(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
+
+impl From<http_stream::Error> for Error {
+    fn from(err: http_stream::Error) -> Self {
+        match err {
+            http_stream::Error::IO(err) => Error::IO(err),
+            http_stream::Error::Parse(()) => Error::Parse(()),
+        }
+    }
+}
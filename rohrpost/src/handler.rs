@@ -0,0 +1,309 @@
+use crate::command::Command;
+use crate::error::Error;
+use crate::http_stream::HttpMsg;
+use crate::telegram_poller::{self, TelegramPoller};
+use crate::telegram_receiver::SendItem;
+use crate::telegram_sender::TelegramSender;
+use crate::telegram_types::{
+    CallbackQuery, InlineQuery, Message, Poll, PreCheckoutQuery, ShippingQuery, Update,
+};
+use async_std::sync;
+use futures::future::{FutureExt, LocalBoxFuture};
+use futures::stream::StreamExt;
+use std::collections::HashMap;
+use std::future::Future;
+use std::rc::Rc;
+
+type Handler<T> = Box<dyn Fn(Rc<TelegramSender>, T) -> LocalBoxFuture<'static, Result<(), Error>>>;
+type CommandHandler =
+    Box<dyn Fn(Rc<TelegramSender>, Message, Command) -> LocalBoxFuture<'static, Result<(), Error>>>;
+type ErrorHandler = Box<dyn Fn(Option<i64>, &Error)>;
+
+fn boxed<T, F, Fut>(handler: F) -> Handler<T>
+where
+    F: Fn(Rc<TelegramSender>, T) -> Fut + 'static,
+    Fut: Future<Output = Result<(), Error>> + 'static,
+{
+    Box::new(move |sender, value| handler(sender, value).boxed_local())
+}
+
+async fn call<T>(
+    handler: &Option<Handler<T>>,
+    sender: Rc<TelegramSender>,
+    value: T,
+) -> Result<(), Error> {
+    match handler {
+        Some(handler) => handler(sender, value).await,
+        None => Ok(()),
+    }
+}
+
+///Dispatcher
+/// routes every update to the async handler registered for its kind.
+/// Updates are taken either from the TelegramReceiver (run_webhook) or from getUpdates (run_polling).
+/// Every handler gets the TelegramSender, errors returned by a handler are passed to the on_error callback.
+/// Messages that start with a registered bot command go to the command handler instead of the message handler.
+pub struct Dispatcher {
+    sender: Rc<TelegramSender>,
+    bot_username: Option<String>,
+    commands: HashMap<String, CommandHandler>,
+    message: Option<Handler<Message>>,
+    edited_message: Option<Handler<Message>>,
+    callback_query: Option<Handler<CallbackQuery>>,
+    inline_query: Option<Handler<InlineQuery>>,
+    shipping_query: Option<Handler<ShippingQuery>>,
+    pre_checkout_query: Option<Handler<PreCheckoutQuery>>,
+    poll: Option<Handler<Poll>>,
+    error: Option<ErrorHandler>,
+}
+
+impl Dispatcher {
+    pub fn new(sender: TelegramSender) -> Self {
+        Dispatcher {
+            sender: Rc::new(sender),
+            bot_username: None,
+            commands: HashMap::new(),
+            message: None,
+            edited_message: None,
+            callback_query: None,
+            inline_query: None,
+            shipping_query: None,
+            pre_checkout_query: None,
+            poll: None,
+            error: None,
+        }
+    }
+    pub fn sender(&self) -> &Rc<TelegramSender> {
+        &self.sender
+    }
+    ///Username of the bot, commands addressed to other bots ("/start@other_bot") are ignored.
+    /// If it is not set, run_webhook and run_polling ask telegram for it with getMe.
+    pub fn bot_username<S: Into<String>>(mut self, bot_username: S) -> Self {
+        self.bot_username = Some(bot_username.into());
+        self
+    }
+    ///Handles messages starting with the command, name is given without the slash
+    pub fn on_command<F, Fut>(mut self, name: &str, handler: F) -> Self
+    where
+        F: Fn(Rc<TelegramSender>, Message, Command) -> Fut + 'static,
+        Fut: Future<Output = Result<(), Error>> + 'static,
+    {
+        let handler: CommandHandler = Box::new(move |sender, message, command| {
+            handler(sender, message, command).boxed_local()
+        });
+        self.commands.insert(String::from(name), handler);
+        self
+    }
+    pub fn on_message<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Rc<TelegramSender>, Message) -> Fut + 'static,
+        Fut: Future<Output = Result<(), Error>> + 'static,
+    {
+        self.message = Some(boxed(handler));
+        self
+    }
+    pub fn on_edited_message<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Rc<TelegramSender>, Message) -> Fut + 'static,
+        Fut: Future<Output = Result<(), Error>> + 'static,
+    {
+        self.edited_message = Some(boxed(handler));
+        self
+    }
+    pub fn on_callback_query<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Rc<TelegramSender>, CallbackQuery) -> Fut + 'static,
+        Fut: Future<Output = Result<(), Error>> + 'static,
+    {
+        self.callback_query = Some(boxed(handler));
+        self
+    }
+    pub fn on_inline_query<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Rc<TelegramSender>, InlineQuery) -> Fut + 'static,
+        Fut: Future<Output = Result<(), Error>> + 'static,
+    {
+        self.inline_query = Some(boxed(handler));
+        self
+    }
+    pub fn on_shipping_query<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Rc<TelegramSender>, ShippingQuery) -> Fut + 'static,
+        Fut: Future<Output = Result<(), Error>> + 'static,
+    {
+        self.shipping_query = Some(boxed(handler));
+        self
+    }
+    pub fn on_pre_checkout_query<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Rc<TelegramSender>, PreCheckoutQuery) -> Fut + 'static,
+        Fut: Future<Output = Result<(), Error>> + 'static,
+    {
+        self.pre_checkout_query = Some(boxed(handler));
+        self
+    }
+    pub fn on_poll<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Rc<TelegramSender>, Poll) -> Fut + 'static,
+        Fut: Future<Output = Result<(), Error>> + 'static,
+    {
+        self.poll = Some(boxed(handler));
+        self
+    }
+    ///Called with the errors of run_webhook and run_polling that can not be returned:
+    /// failed handlers, updates that could not be deserialized and failed getUpdates calls.
+    /// update_id is set if the error belongs to an update. Without a callback these errors are ignored.
+    pub fn on_error<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(Option<i64>, &Error) + 'static,
+    {
+        self.error = Some(Box::new(handler));
+        self
+    }
+    fn error(&self, update_id: Option<i64>, err: &Error) {
+        if let Some(handler) = &self.error {
+            handler(update_id, err);
+        }
+    }
+    ///Calls the handler registered for the kind of the update.
+    /// Updates without a matching handler are ignored.
+    pub async fn dispatch(&self, update: Update) -> Result<(), Error> {
+        let sender = self.sender.clone();
+        if let Some(message) = update.message {
+            self.dispatch_message(sender, message).await
+        } else if let Some(message) = update.edited_message {
+            call(&self.edited_message, sender, message).await
+        } else if let Some(query) = update.callback_query {
+            call(&self.callback_query, sender, query).await
+        } else if let Some(query) = update.inline_query {
+            call(&self.inline_query, sender, query).await
+        } else if let Some(query) = update.shipping_query {
+            call(&self.shipping_query, sender, query).await
+        } else if let Some(query) = update.pre_checkout_query {
+            call(&self.pre_checkout_query, sender, query).await
+        } else if let Some(poll) = update.poll {
+            call(&self.poll, sender, poll).await
+        } else {
+            Ok(())
+        }
+    }
+    async fn dispatch_message(
+        &self,
+        sender: Rc<TelegramSender>,
+        message: Message,
+    ) -> Result<(), Error> {
+        if let Some(command) = Command::parse(&message) {
+            if let Some(bot_username) = &self.bot_username {
+                if !command.is_addressed_to(bot_username) {
+                    return Ok(());
+                }
+            }
+            if let Some(handler) = self.commands.get(&command.name) {
+                return handler(sender, message, command).await;
+            }
+        }
+        call(&self.message, sender, message).await
+    }
+    async fn handle(&self, update: Update) {
+        let update_id = update.update_id;
+        if let Err(err) = self.dispatch(update).await {
+            self.error(Some(update_id), &err);
+        }
+    }
+    ///Fills in the username of the bot from getMe, unless it was set explicitly
+    async fn load_bot_username(&mut self) -> Result<(), Error> {
+        if self.bot_username.is_none() {
+            self.bot_username = self.sender.me().await?.username;
+        }
+        Ok(())
+    }
+    ///Handles the updates the TelegramReceiver passes on, until the receiver stops.
+    /// Every request is acknowledged with 200 before its update is handled, so telegram does not send it again.
+    /// Returns an error if the username of the bot could not be loaded with getMe.
+    pub async fn run_webhook(mut self, http_recv: sync::Receiver<SendItem>) -> Result<(), Error> {
+        self.load_bot_username().await?;
+        while let Some((http_msg, response_channel)) = http_recv.recv().await {
+            let update = serde_json::from_slice::<Update>(http_msg.get_body());
+            response_channel.send(HttpMsg::new_respone(200)).await;
+            match update {
+                Ok(update) => self.handle(update).await,
+                Err(err) => self.error(None, &Error::Json(err)),
+            }
+        }
+        Ok(())
+    }
+    ///Polls for updates with getUpdates and handles them.
+    /// Only returns if the username of the bot could not be loaded with getMe.
+    pub async fn run_polling(mut self, config: telegram_poller::Config) -> Result<(), Error> {
+        self.load_bot_username().await?;
+        let sender = self.sender.clone();
+        let mut updates = TelegramPoller::new(&sender, config).into_stream();
+        while let Some(update) = updates.next().await {
+            match update {
+                Ok(update) => self.handle(update).await,
+                Err(err) => self.error(None, &err),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telegram_sender;
+    use futures::executor::block_on;
+    use std::cell::RefCell;
+
+    #[test]
+    fn updates_are_routed_by_kind() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let on_message = seen.clone();
+        let on_query = seen.clone();
+        let sender = TelegramSender::new(telegram_sender::Config::new(String::from("123:abc")));
+        let dispatcher = Dispatcher::new(sender)
+            .on_message(move |_, message: Message| {
+                on_message.borrow_mut().push(message.message_id);
+                async { Ok(()) }
+            })
+            .on_callback_query(move |_, query: CallbackQuery| {
+                on_query.borrow_mut().push(query.id.parse().unwrap());
+                async { Ok(()) }
+            });
+        let update = r#"{"update_id":1,"callback_query":{"id":"7","chat_instance":"1","from":{"id":5,"is_bot":false,"first_name":"A"}}}"#;
+        block_on(dispatcher.dispatch(serde_json::from_str(update).unwrap())).unwrap();
+        //no handler for inline queries, the update is dropped
+        let update = r#"{"update_id":2,"inline_query":{"id":"8","query":"","offset":"","from":{"id":5,"is_bot":false,"first_name":"A"}}}"#;
+        block_on(dispatcher.dispatch(serde_json::from_str(update).unwrap())).unwrap();
+        assert_eq!(*seen.borrow(), vec![7]);
+    }
+
+    #[test]
+    fn handler_errors_go_to_the_error_callback() {
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let on_error = errors.clone();
+        let sender = TelegramSender::new(telegram_sender::Config::new(String::from("123:abc")));
+        let dispatcher = Dispatcher::new(sender)
+            .on_message(|_, _| async { Err(Error::Passport("failed")) })
+            .on_error(move |update_id, err| {
+                on_error.borrow_mut().push((update_id, err.to_string()));
+            });
+        let update = r#"{"update_id":3,"message":{"message_id":1,"date":0,"chat":{"id":5,"type":"private"},"text":"hi"}}"#;
+        block_on(dispatcher.handle(serde_json::from_str(update).unwrap()));
+        assert_eq!(
+            *errors.borrow(),
+            vec![(Some(3), String::from("passport error: failed"))]
+        );
+    }
+
+    #[test]
+    fn run_polling_returns_the_get_me_error() {
+        let (listener, config) = telegram_sender::mock_server::bind();
+        //nothing listens on the port anymore
+        drop(listener);
+        let config = config.retry_policy(crate::retry::RetryPolicy::none());
+        let dispatcher = Dispatcher::new(TelegramSender::new(config));
+        let result = block_on(dispatcher.run_polling(telegram_poller::Config::new(0)));
+        assert!(matches!(result, Err(Error::IO(_))));
+    }
+}
@@ -9,3 +9,73 @@ pub enum Or<T, U> {
     A(T),
     B(U),
 }
+
+///Declares an enum for a field that telegram fills with one of a few strings.
+/// Strings this version does not know end up in Unknown, so new api values do not break deserialization.
+macro_rules! string_enum {
+    ($(#[$meta:meta])* pub enum $name:ident { $($variant:ident = $value:expr,)* }) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+            Unknown(String),
+        }
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    other => $name::Unknown(String::from(other)),
+                }
+            }
+        }
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let value = String::deserialize(deserializer)?;
+                Ok($name::from(value.as_str()))
+            }
+        }
+    };
+}
+pub(crate) use string_enum;
+
+#[cfg(test)]
+mod tests {
+    use crate::telegram_types::ChatType;
+
+    #[test]
+    fn unknown_strings_are_kept() {
+        let types: Vec<ChatType> = serde_json::from_str(r#"["private","forum"]"#).unwrap();
+        assert_eq!(
+            types,
+            vec![ChatType::Private, ChatType::Unknown(String::from("forum"))]
+        );
+        assert_eq!(
+            serde_json::to_string(&types).unwrap(),
+            r#"["private","forum"]"#
+        );
+    }
+}
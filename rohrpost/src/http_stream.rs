@@ -1,4 +1,4 @@
-use futures::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use futures::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, FutureExt};
 use http;
 use http::header::{HeaderMap, HeaderName, HeaderValue};
 use http::request::Request;
@@ -134,7 +134,7 @@ where
             return Err(());
         }
         let header_name = header_name.ok().unwrap();
-        let header_value = HeaderValue::from_bytes(&line[split + 1..]);
+        let header_value = HeaderValue::from_bytes(trim(&line[split + 1..]));
         if header_value.is_err() {
             //println!("Could not parse header value");
             return Err(());
@@ -143,6 +143,23 @@ where
         Ok((header_name, header_value))
     }
     pub async fn read(&mut self) -> std::result::Result<HttpMsg, Error> {
+        let mut msg = self.read_head().await?;
+        //requests without content-length have no body
+        let content_length = msg.content_length()?.unwrap_or(0);
+        let mut buf: Vec<u8> = Vec::with_capacity(content_length as usize);
+        let ret = self.read_body(content_length, &mut buf).await;
+        if ret.is_err() {
+            return Err(Error::IO(ret.err().unwrap()));
+        }
+        match &mut msg {
+            HttpMsg::Request(req) => *req.body_mut() = buf,
+            HttpMsg::Response(resp) => *resp.body_mut() = buf,
+        }
+        Ok(msg)
+    }
+    ///Reads the request or status line and the headers, the body is left in the stream.
+    /// It has to be consumed with read_body before the next message can be read.
+    pub async fn read_head(&mut self) -> std::result::Result<HttpMsg, Error> {
         //first read the request/status line
         let request_status_line = self.read_until_crlf().await;
         if request_status_line.is_err() {
@@ -179,58 +196,88 @@ where
                 return Err(Error::Parse(result.err().unwrap()));
             }
             let (header_name, header_value) = result.ok().unwrap();
-            header_map.insert(header_name, header_value);
+            header_map.append(header_name, header_value);
         }
-        //println!("Header Map: {:?}", header_map);
-        let body = if header_map.contains_key(http::header::CONTENT_LENGTH) {
-            let content_length = header_map.get(http::header::CONTENT_LENGTH).unwrap();
-            let content_length = content_length.to_str();
-            if content_length.is_err() {
-                println!("could not convert content length to string");
-                return Err(Error::Parse(()));
-            }
-            let content_length = content_length.unwrap();
-            let content_length = String::from(content_length.trim());
-            let content_length = content_length.parse::<usize>();
-            if content_length.is_err() {
-                println!("could not convert content length to usize");
-                return Err(Error::Parse(()));
-            }
-            let content_length = content_length.unwrap();
-            let mut buf: Vec<u8> = Vec::with_capacity(content_length);
-            for _ in 0..content_length {
-                buf.push(0);
-            }
-            //println!("start read body");
-            let ret = self.stream.read_exact(&mut buf[..]).await;
-            //println!("end read body");
-            if ret.is_err() {
-                //println!("could not read body");
-                return Err(Error::IO(ret.err().unwrap()));
-            }
-            buf
-        } else {
-            Vec::new()
-        };
         if request.is_ok() {
             let (method, uri, version) = request.unwrap();
-            let request = Request::builder()
+            let mut request = Request::builder()
                 .method(method)
                 .uri(uri)
                 .version(version)
-                .body(body)
+                .body(Vec::new())
                 .unwrap();
+            *request.headers_mut() = header_map;
             Ok(HttpMsg::Request(request))
         } else {
             let (version, status_code) = status.unwrap();
-            let response = Response::builder()
+            let mut response = Response::builder()
                 .version(version)
                 .status(status_code)
-                .body(body)
+                .body(Vec::new())
                 .unwrap();
+            *response.headers_mut() = header_map;
             Ok(HttpMsg::Response(response))
         }
     }
+    ///Checks without waiting whether the other side closed the connection.
+    /// Data nobody asked for counts as closed too, the connection is out of step then.
+    pub fn is_closed(&mut self) -> bool {
+        let mut buf = [0u8; 1];
+        self.stream.read(&mut buf).now_or_never().is_some()
+    }
+    ///Copies the next content_length bytes of the stream, the body of the message read by read_head, into writer
+    pub async fn read_body<W>(&mut self, content_length: u64, writer: &mut W) -> io::Result<()>
+    where
+        W: AsyncWrite + std::marker::Unpin,
+    {
+        let copied = futures::io::copy((&mut self.stream).take(content_length), writer).await?;
+        if copied < content_length {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "stream ended before the end of the body",
+            ));
+        }
+        Ok(())
+    }
+    ///Copies the body from reader into the stream, after the head was written with write.
+    /// Without content_length the body is sent with chunked transfer-encoding.
+    pub async fn write_body<R>(
+        &mut self,
+        mut reader: R,
+        content_length: Option<u64>,
+    ) -> io::Result<()>
+    where
+        R: AsyncRead + std::marker::Unpin,
+    {
+        match content_length {
+            Some(content_length) => {
+                let copied =
+                    futures::io::copy(reader.take(content_length), &mut self.stream).await?;
+                if copied < content_length {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "body is shorter than its content-length",
+                    ));
+                }
+            }
+            None => {
+                let mut buf = vec![0u8; 16 * 1024];
+                loop {
+                    let read = reader.read(&mut buf).await?;
+                    //the chunk of size 0 ends the body
+                    self.stream
+                        .write_all(format!("{:x}\r\n", read).as_bytes())
+                        .await?;
+                    self.stream.write_all(&buf[..read]).await?;
+                    self.stream.write_all(b"\r\n").await?;
+                    if read == 0 {
+                        break;
+                    }
+                }
+            }
+        }
+        self.stream.flush().await
+    }
     pub async fn write(&mut self, msg: HttpMsg) -> io::Result<()> {
         let mut send: Vec<u8> = Vec::new();
         match msg {
@@ -302,12 +349,23 @@ where
                 }
             }
         }
-        println!("{}", String::from_utf8(send.clone()).unwrap());
         self.stream.write_all(&send[..]).await?;
         Ok(())
     }
 }
 
+///Strips optional whitespace around header values
+fn trim(mut value: &[u8]) -> &[u8] {
+    while let [b' ', rest @ ..] | [b'\t', rest @ ..] = value {
+        value = rest;
+    }
+    while let [rest @ .., b' '] | [rest @ .., b'\t'] = value {
+        value = rest;
+    }
+    value
+}
+
+#[derive(Debug)]
 pub enum Error {
     IO(io::Error),
     Parse(()),
@@ -338,6 +396,38 @@ impl HttpMsg {
             HttpMsg::Response(resp) => resp,
         }
     }
+    ///Length of the body given by the content-length header, None without the header
+    pub fn content_length(&self) -> std::result::Result<Option<u64>, Error> {
+        match self.get_headers().get(http::header::CONTENT_LENGTH) {
+            Some(content_length) => {
+                let content_length = content_length.to_str();
+                if content_length.is_err() {
+                    println!("could not convert content length to string");
+                    return Err(Error::Parse(()));
+                }
+                let content_length = content_length.unwrap().trim().parse::<u64>();
+                if content_length.is_err() {
+                    println!("could not convert content length to u64");
+                    return Err(Error::Parse(()));
+                }
+                Ok(Some(content_length.unwrap()))
+            }
+            None => Ok(None),
+        }
+    }
+    pub fn get_headers(&self) -> &HeaderMap {
+        match self {
+            HttpMsg::Request(req) => req.headers(),
+            HttpMsg::Response(resp) => resp.headers(),
+        }
+    }
+    ///False if the other side asked to close the connection after this message
+    pub fn keep_alive(&self) -> bool {
+        match self.get_headers().get(http::header::CONNECTION) {
+            Some(value) => !value.as_bytes().eq_ignore_ascii_case(b"close"),
+            None => true,
+        }
+    }
     pub fn new_respone(status: u16) -> Self {
         let response = http::Response::builder()
             .version(http::version::Version::HTTP_11)
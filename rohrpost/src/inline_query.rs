@@ -0,0 +1,50 @@
+use crate::telegram_methods::answerInlineQueryBuilder;
+use crate::telegram_types::{InlineQuery, InlineQueryResult};
+use std::ops::Range;
+
+///Telegram accepts at most 50 results per answer
+pub const MAX_RESULTS: usize = 50;
+
+///Offset based pagination for inline queries.
+/// The offset of the query is the index of the first result on the page, an empty or invalid offset starts at 0.
+/// Returns the range of results to send and the next_offset for the answer, which is empty on the last page.
+pub fn page(offset: &str, page_size: usize, total: usize) -> (Range<usize>, String) {
+    let page_size = page_size.clamp(1, MAX_RESULTS);
+    let start = offset.parse::<usize>().unwrap_or(0).min(total);
+    let end = (start + page_size).min(total);
+    let next_offset = if end < total {
+        end.to_string()
+    } else {
+        String::new()
+    };
+    (start..end, next_offset)
+}
+
+///Builds the answer to the query with the page of results the query asked for.
+/// Further options like cache_time can be set on the returned builder.
+pub fn answer_page(
+    query: &InlineQuery,
+    mut results: Vec<InlineQueryResult>,
+    page_size: usize,
+) -> answerInlineQueryBuilder {
+    let (range, next_offset) = page(&query.offset, page_size, results.len());
+    let mut builder = answerInlineQueryBuilder::default();
+    builder
+        .inline_query_id(query.id.clone())
+        .results(results.drain(range).collect())
+        .next_offset(next_offset);
+    builder
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pages_follow_the_offset() {
+        assert_eq!(page("", 20, 45), (0..20, String::from("20")));
+        assert_eq!(page("20", 20, 45), (20..40, String::from("40")));
+        assert_eq!(page("40", 20, 45), (40..45, String::new()));
+        assert_eq!(page("garbage", 100, 70), (0..50, String::from("50")));
+    }
+}
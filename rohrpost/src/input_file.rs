@@ -0,0 +1,268 @@
+use crate::helpers::Or;
+use crate::telegram_types::{
+    InputMedia, InputMediaAnimation, InputMediaAudio, InputMediaDocument, InputMediaPhoto,
+    InputMediaVideo,
+};
+use futures::io::AsyncRead;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+type Reader = Box<dyn AsyncRead + Send + Unpin>;
+
+#[derive(Clone)]
+enum Source {
+    Memory(Arc<Vec<u8>>),
+    Path(PathBuf),
+    //a reader can only be consumed once, clones share it
+    Reader(Arc<Mutex<Option<Reader>>>),
+}
+
+//every InputFile gets its own id, clones share it
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+///A file that gets uploaded together with the method using multipart/form-data.
+/// Wherever the api accepts an InputFile the sender switches from a JSON body to a multipart body.
+/// The file itself serializes to "attach://<attach_name>".
+#[derive(Clone)]
+pub struct InputFile {
+    id: u64,
+    file_name: String,
+    source: Source,
+}
+
+impl InputFile {
+    ///Upload bytes that are already in memory
+    pub fn memory<N, D>(file_name: N, data: D) -> Self
+    where
+        N: Into<String>,
+        D: Into<Vec<u8>>,
+    {
+        InputFile {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            file_name: file_name.into(),
+            source: Source::Memory(Arc::new(data.into())),
+        }
+    }
+    ///Upload a local file, it is read when the method is sent
+    pub fn path<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| String::from("file"));
+        InputFile {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            file_name,
+            source: Source::Path(path),
+        }
+    }
+    ///Upload everything the reader returns. The reader can only be sent once.
+    pub fn reader<N, R>(file_name: N, reader: R) -> Self
+    where
+        N: Into<String>,
+        R: AsyncRead + Send + Unpin + 'static,
+    {
+        InputFile {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            file_name: file_name.into(),
+            source: Source::Reader(Arc::new(Mutex::new(Some(Box::new(reader))))),
+        }
+    }
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+    ///Name of the multipart field the file is sent under when it is referenced as "attach://<attach_name>"
+    pub fn attach_name(&self) -> String {
+        format!("file{}", self.id)
+    }
+    ///True for clones of the same file
+    pub(crate) fn same(&self, other: &InputFile) -> bool {
+        self.id == other.id
+    }
+    ///Size of the file, None for readers
+    pub(crate) async fn len(&self) -> io::Result<Option<u64>> {
+        match &self.source {
+            Source::Memory(data) => Ok(Some(data.len() as u64)),
+            Source::Path(path) => Ok(Some(async_std::fs::metadata(path).await?.len())),
+            Source::Reader(_) => Ok(None),
+        }
+    }
+    ///Opens the content of the file for reading, a file on disk is opened again for every call
+    pub(crate) async fn open(&self) -> io::Result<Box<dyn AsyncRead + Send + Unpin + '_>> {
+        match &self.source {
+            Source::Memory(data) => Ok(Box::new(&data[..])),
+            Source::Path(path) => Ok(Box::new(async_std::fs::File::open(path).await?)),
+            Source::Reader(reader) => match reader.lock().unwrap().take() {
+                Some(reader) => Ok(reader),
+                //not UnexpectedEof, a retry would fail the same way
+                None => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "the reader of this InputFile was already consumed",
+                )),
+            },
+        }
+    }
+}
+
+impl fmt::Debug for InputFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InputFile")
+            .field("file_name", &self.file_name)
+            .finish()
+    }
+}
+
+impl Serialize for InputFile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format!("attach://{}", self.attach_name()))
+    }
+}
+
+impl<'de> Deserialize<'de> for InputFile {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Err(de::Error::custom("InputFile can not be received"))
+    }
+}
+
+///The InputFiles a method uploads, see TelegramMethod::input_files
+#[derive(Default)]
+pub struct InputFiles<'a> {
+    fields: Vec<(&'static str, &'a InputFile)>,
+    attached: Vec<&'a InputFile>,
+}
+
+impl<'a> InputFiles<'a> {
+    ///A file in a field of the method, it is uploaded under the name of the field
+    pub fn field<T>(mut self, name: &'static str, value: &'a T) -> Self
+    where
+        T: ContainsFiles,
+    {
+        let mut files = Vec::new();
+        value.files(&mut files);
+        self.fields
+            .extend(files.into_iter().map(|file| (name, file)));
+        self
+    }
+    ///Files nested in a field, e.g. in InputMedia. They are uploaded under their attach_name.
+    pub fn attached<T>(mut self, value: &'a T) -> Self
+    where
+        T: ContainsFiles,
+    {
+        value.files(&mut self.attached);
+        self
+    }
+    pub(crate) fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.attached.is_empty()
+    }
+    ///The file in the field with the given name
+    pub(crate) fn get(&self, name: &str) -> Option<&'a InputFile> {
+        self.fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, file)| *file)
+    }
+    ///The nested files, clones of the same file only once
+    pub(crate) fn attachments(&self) -> Vec<&'a InputFile> {
+        let mut attachments: Vec<&InputFile> = Vec::new();
+        for file in &self.attached {
+            if !attachments.iter().any(|other| other.same(file)) {
+                attachments.push(file);
+            }
+        }
+        attachments
+    }
+}
+
+///Values that can hold an InputFile
+pub trait ContainsFiles {
+    ///Appends the InputFiles of the value to files
+    fn files<'a>(&'a self, files: &mut Vec<&'a InputFile>);
+}
+
+impl ContainsFiles for InputFile {
+    fn files<'a>(&'a self, files: &mut Vec<&'a InputFile>) {
+        files.push(self);
+    }
+}
+
+impl<B> ContainsFiles for Or<InputFile, B> {
+    fn files<'a>(&'a self, files: &mut Vec<&'a InputFile>) {
+        if let Or::A(file) = self {
+            files.push(file);
+        }
+    }
+}
+
+impl<T> ContainsFiles for Option<T>
+where
+    T: ContainsFiles,
+{
+    fn files<'a>(&'a self, files: &mut Vec<&'a InputFile>) {
+        if let Some(value) = self {
+            value.files(files);
+        }
+    }
+}
+
+impl<T> ContainsFiles for Vec<T>
+where
+    T: ContainsFiles,
+{
+    fn files<'a>(&'a self, files: &mut Vec<&'a InputFile>) {
+        for value in self {
+            value.files(files);
+        }
+    }
+}
+
+impl ContainsFiles for InputMedia {
+    fn files<'a>(&'a self, files: &mut Vec<&'a InputFile>) {
+        match self {
+            InputMedia::Photo(media) => media.files(files),
+            InputMedia::Video(media) => media.files(files),
+            InputMedia::Animation(media) => media.files(files),
+            InputMedia::Audio(media) => media.files(files),
+            InputMedia::Document(media) => media.files(files),
+        }
+    }
+}
+
+impl ContainsFiles for InputMediaPhoto {
+    fn files<'a>(&'a self, files: &mut Vec<&'a InputFile>) {
+        self.media.files(files);
+    }
+}
+
+macro_rules! media_with_thumb {
+    ($($media:ty),*) => {
+        $(
+            impl ContainsFiles for $media {
+                fn files<'a>(&'a self, files: &mut Vec<&'a InputFile>) {
+                    self.media.files(files);
+                    self.thumb.files(files);
+                }
+            }
+        )*
+    };
+}
+
+media_with_thumb!(
+    InputMediaVideo,
+    InputMediaAnimation,
+    InputMediaAudio,
+    InputMediaDocument
+);
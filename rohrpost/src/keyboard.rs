@@ -0,0 +1,176 @@
+use crate::telegram_types::{
+    ForceReply, InlineKeyboardButton, InlineKeyboardMarkup, KeyboardButton, ReplyKeyboardMarkup,
+    ReplyKeyboardRemove,
+};
+
+///Splits the buttons into rows of at most columns buttons
+fn rows<T, I>(buttons: I, columns: usize) -> Vec<Vec<T>>
+where
+    I: IntoIterator<Item = T>,
+{
+    let columns = columns.max(1);
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for button in buttons {
+        match rows.last_mut() {
+            Some(row) if row.len() < columns => row.push(button),
+            _ => rows.push(vec![button]),
+        }
+    }
+    rows
+}
+
+impl InlineKeyboardButton {
+    fn with_text(text: String) -> Self {
+        InlineKeyboardButton {
+            text,
+            url: None,
+            login_url: None,
+            callback_data: None,
+            switch_inline_query: None,
+            switch_inline_query_current_chat: None,
+            callback_game: None,
+            pay: None,
+        }
+    }
+    ///A button that sends callback_data to the bot in a CallbackQuery
+    pub fn callback<T, D>(text: T, callback_data: D) -> Self
+    where
+        T: Into<String>,
+        D: Into<String>,
+    {
+        InlineKeyboardButton {
+            callback_data: Some(callback_data.into()),
+            ..InlineKeyboardButton::with_text(text.into())
+        }
+    }
+    ///A button that opens the url
+    pub fn url<T, U>(text: T, url: U) -> Self
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        InlineKeyboardButton {
+            url: Some(url.into()),
+            ..InlineKeyboardButton::with_text(text.into())
+        }
+    }
+    ///A button that starts an inline query for the bot in the current chat
+    pub fn switch_inline_query_current_chat<T, Q>(text: T, query: Q) -> Self
+    where
+        T: Into<String>,
+        Q: Into<String>,
+    {
+        InlineKeyboardButton {
+            switch_inline_query_current_chat: Some(query.into()),
+            ..InlineKeyboardButton::with_text(text.into())
+        }
+    }
+}
+
+impl InlineKeyboardMarkup {
+    pub fn new(inline_keyboard: Vec<Vec<InlineKeyboardButton>>) -> Self {
+        InlineKeyboardMarkup { inline_keyboard }
+    }
+    ///Arranges the buttons in rows of columns buttons
+    pub fn grid<I>(buttons: I, columns: usize) -> Self
+    where
+        I: IntoIterator<Item = InlineKeyboardButton>,
+    {
+        InlineKeyboardMarkup::new(rows(buttons, columns))
+    }
+    ///Arranges callback buttons built from (label, callback_data) pairs in rows of columns buttons
+    pub fn callback_grid<I, T, D>(buttons: I, columns: usize) -> Self
+    where
+        I: IntoIterator<Item = (T, D)>,
+        T: Into<String>,
+        D: Into<String>,
+    {
+        InlineKeyboardMarkup::grid(
+            buttons
+                .into_iter()
+                .map(|(text, data)| InlineKeyboardButton::callback(text, data)),
+            columns,
+        )
+    }
+}
+
+impl KeyboardButton {
+    ///A button that sends its text as message
+    pub fn new<T: Into<String>>(text: T) -> Self {
+        KeyboardButton {
+            text: text.into(),
+            request_contact: None,
+            request_location: None,
+        }
+    }
+}
+
+impl ReplyKeyboardMarkup {
+    pub fn new(keyboard: Vec<Vec<KeyboardButton>>) -> Self {
+        ReplyKeyboardMarkup {
+            keyboard,
+            resize_keyboard: None,
+            one_time_keyboard: None,
+            selective: None,
+        }
+    }
+    ///Arranges text buttons with the given labels in rows of columns buttons
+    pub fn grid<I, T>(labels: I, columns: usize) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        ReplyKeyboardMarkup::new(rows(labels.into_iter().map(KeyboardButton::new), columns))
+    }
+    ///Fit the keyboard to the buttons instead of the height of the standard keyboard
+    pub fn resize(mut self) -> Self {
+        self.resize_keyboard = Some(true);
+        self
+    }
+    ///Hide the keyboard after a button was pressed
+    pub fn one_time(mut self) -> Self {
+        self.one_time_keyboard = Some(true);
+        self
+    }
+    ///Only show the keyboard to mentioned users and the sender of the replied to message
+    pub fn selective(mut self) -> Self {
+        self.selective = Some(true);
+        self
+    }
+}
+
+impl Default for ReplyKeyboardRemove {
+    fn default() -> Self {
+        ReplyKeyboardRemove {
+            remove_keyboard: true,
+            selective: None,
+        }
+    }
+}
+
+impl Default for ForceReply {
+    fn default() -> Self {
+        ForceReply {
+            force_reply: true,
+            selective: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn callback_grid_fills_rows() {
+        let markup = InlineKeyboardMarkup::callback_grid(
+            (1..=5).map(|day| (format!("Day {}", day), format!("day:{}", day))),
+            2,
+        );
+        let rows: Vec<usize> = markup.inline_keyboard.iter().map(Vec::len).collect();
+        assert_eq!(rows, vec![2, 2, 1]);
+        let last = &markup.inline_keyboard[2][0];
+        assert_eq!(last.text, "Day 5");
+        assert_eq!(last.callback_data.as_deref(), Some("day:5"));
+    }
+}
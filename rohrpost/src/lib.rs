@@ -4,13 +4,35 @@ extern crate futures;
 #[macro_use]
 extern crate derive_builder;
 
+pub mod chat_id;
+pub mod command;
+pub mod download;
+pub mod error;
+pub mod handler;
 pub mod helpers;
 pub mod http_stream;
+pub mod inline_query;
+pub mod input_file;
+pub mod keyboard;
+pub mod message_kind;
+pub mod multipart;
+pub mod passport;
+pub mod payments;
+pub mod rate_limit;
+pub mod retry;
 pub mod telegram_methods;
+pub mod telegram_poller;
 pub mod telegram_receiver;
 pub mod telegram_sender;
 pub mod telegram_types;
 
+pub use chat_id::ChatId;
+pub use command::Command;
+pub use error::Error;
+pub use handler::Dispatcher;
+pub use input_file::InputFile;
+pub use message_kind::MessageKind;
+pub use telegram_poller::TelegramPoller;
 pub use telegram_receiver::TelegramReceiver;
 pub use telegram_sender::TelegramSender;
 
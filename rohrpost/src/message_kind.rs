@@ -0,0 +1,179 @@
+use crate::telegram_types::{
+    Animation, Audio, Contact, Document, Game, Invoice, Location, Message, MessageEntity,
+    PassportData, PhotoSize, Poll, Sticker, SuccessfulPayment, User, Venue, Video, VideoNote,
+    Voice,
+};
+
+///The content of a message.
+/// Telegram sets exactly one of the content fields of a Message, MessageKind is a view on that field
+/// so that handlers can match on it instead of probing the Options one by one.
+pub enum MessageKind<'a> {
+    Text {
+        text: &'a str,
+        entities: &'a [MessageEntity],
+    },
+    Audio {
+        audio: &'a Audio,
+        caption: Option<&'a str>,
+    },
+    Document {
+        document: &'a Document,
+        caption: Option<&'a str>,
+    },
+    Animation {
+        animation: &'a Animation,
+        caption: Option<&'a str>,
+    },
+    Game(&'a Game),
+    Photo {
+        sizes: &'a [PhotoSize],
+        caption: Option<&'a str>,
+    },
+    Sticker(&'a Sticker),
+    Video {
+        video: &'a Video,
+        caption: Option<&'a str>,
+    },
+    Voice {
+        voice: &'a Voice,
+        caption: Option<&'a str>,
+    },
+    VideoNote(&'a VideoNote),
+    Contact(&'a Contact),
+    Location(&'a Location),
+    Venue(&'a Venue),
+    Poll(&'a Poll),
+    NewChatMembers(&'a [User]),
+    LeftChatMember(&'a User),
+    NewChatTitle(&'a str),
+    NewChatPhoto(&'a [PhotoSize]),
+    DeleteChatPhoto,
+    GroupChatCreated,
+    SupergroupChatCreated,
+    ChannelChatCreated,
+    MigrateToChatId(i64),
+    MigrateFromChatId(i64),
+    PinnedMessage(&'a Message),
+    Invoice(&'a Invoice),
+    SuccessfulPayment(&'a SuccessfulPayment),
+    ConnectedWebsite(&'a str),
+    PassportData(&'a PassportData),
+    ///Content this version of the crate does not know about
+    Unknown,
+}
+
+impl Message {
+    ///Returns which kind of content the message carries
+    pub fn kind(&self) -> MessageKind<'_> {
+        let caption = self.caption.as_deref();
+        //animations also fill document and venues also fill location for older clients,
+        //so they have to be checked first
+        if let Some(text) = &self.text {
+            MessageKind::Text {
+                text,
+                entities: self.entities.as_deref().unwrap_or(&[]),
+            }
+        } else if let Some(animation) = &self.animation {
+            MessageKind::Animation { animation, caption }
+        } else if let Some(audio) = &self.audio {
+            MessageKind::Audio { audio, caption }
+        } else if let Some(document) = &self.document {
+            MessageKind::Document { document, caption }
+        } else if let Some(game) = &self.game {
+            MessageKind::Game(game)
+        } else if let Some(sizes) = &self.photo {
+            MessageKind::Photo { sizes, caption }
+        } else if let Some(sticker) = &self.sticker {
+            MessageKind::Sticker(sticker)
+        } else if let Some(video) = &self.video {
+            MessageKind::Video { video, caption }
+        } else if let Some(voice) = &self.voice {
+            MessageKind::Voice { voice, caption }
+        } else if let Some(video_note) = &self.video_note {
+            MessageKind::VideoNote(video_note)
+        } else if let Some(contact) = &self.contact {
+            MessageKind::Contact(contact)
+        } else if let Some(venue) = &self.venue {
+            MessageKind::Venue(venue)
+        } else if let Some(location) = &self.location {
+            MessageKind::Location(location)
+        } else if let Some(poll) = &self.poll {
+            MessageKind::Poll(poll)
+        } else if let Some(users) = &self.new_chat_members {
+            MessageKind::NewChatMembers(users)
+        } else if let Some(user) = &self.left_chat_member {
+            MessageKind::LeftChatMember(user)
+        } else if let Some(title) = &self.new_chat_title {
+            MessageKind::NewChatTitle(title)
+        } else if let Some(sizes) = &self.new_chat_photo {
+            MessageKind::NewChatPhoto(sizes)
+        } else if self.delete_chat_photo.is_some() {
+            MessageKind::DeleteChatPhoto
+        } else if self.group_chat_created.is_some() {
+            MessageKind::GroupChatCreated
+        } else if self.supergroup_chat_created.is_some() {
+            MessageKind::SupergroupChatCreated
+        } else if self.channel_chat_created.is_some() {
+            MessageKind::ChannelChatCreated
+        } else if let Some(chat_id) = self.migrate_to_chat_id {
+            MessageKind::MigrateToChatId(chat_id)
+        } else if let Some(chat_id) = self.migrate_from_chat_id {
+            MessageKind::MigrateFromChatId(chat_id)
+        } else if let Some(message) = &self.pinned_message {
+            MessageKind::PinnedMessage(message)
+        } else if let Some(invoice) = &self.invoice {
+            MessageKind::Invoice(invoice)
+        } else if let Some(payment) = &self.successful_payment {
+            MessageKind::SuccessfulPayment(payment)
+        } else if let Some(website) = &self.connected_website {
+            MessageKind::ConnectedWebsite(website)
+        } else if let Some(data) = &self.passport_data {
+            MessageKind::PassportData(data)
+        } else {
+            MessageKind::Unknown
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(content: &str) -> Message {
+        let json = format!(
+            r#"{{"message_id":1,"date":0,"chat":{{"id":5,"type":"private"}},{}}}"#,
+            content
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn kind_picks_the_content_field() {
+        match message(r#""text":"hi""#).kind() {
+            MessageKind::Text { text, entities } => {
+                assert_eq!(text, "hi");
+                assert!(entities.is_empty());
+            }
+            _ => panic!("expected a text message"),
+        }
+        let animation = message(
+            r#""caption":"look","animation":{"file_id":"a","width":1,"height":1,"duration":1},"document":{"file_id":"a"}"#,
+        );
+        match animation.kind() {
+            MessageKind::Animation { animation, caption } => {
+                assert_eq!(animation.file_id, "a");
+                assert_eq!(caption, Some("look"));
+            }
+            _ => panic!("expected an animation"),
+        }
+        let joined = message(r#""new_chat_members":[{"id":7,"is_bot":false,"first_name":"B"}]"#);
+        match joined.kind() {
+            MessageKind::NewChatMembers(users) => assert_eq!(users[0].id, 7),
+            _ => panic!("expected new chat members"),
+        }
+        assert!(matches!(
+            message(r#""extra":1"#).kind(),
+            MessageKind::Unknown
+        ));
+    }
+}
@@ -0,0 +1,322 @@
+use crate::error::Error;
+use crate::input_file::InputFile;
+use crate::telegram_methods::TelegramMethod;
+use futures::io::{AsyncRead, AsyncReadExt};
+use serde::ser::Serialize;
+use serde_json::Value;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io;
+
+///A request body. Files are not read into memory, they are streamed from their InputFile when the body is sent.
+pub struct Body {
+    parts: Vec<Part>,
+    content_type: String,
+}
+
+enum Part {
+    Bytes(Vec<u8>),
+    File(InputFile, Option<u64>),
+}
+
+impl Body {
+    ///A body that is already completely in memory
+    pub fn bytes(body: Vec<u8>, content_type: String) -> Self {
+        Body {
+            parts: vec![Part::Bytes(body)],
+            content_type,
+        }
+    }
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+    ///Size of the body, None if it contains a reader of unknown length.
+    /// Such bodies are sent with chunked transfer-encoding.
+    pub fn content_length(&self) -> Option<u64> {
+        self.parts.iter().try_fold(0, |length, part| match part {
+            Part::Bytes(bytes) => Some(length + bytes.len() as u64),
+            Part::File(_, file_length) => file_length.map(|file_length| length + file_length),
+        })
+    }
+    ///Opens the files and returns a reader over the whole body.
+    /// Can be called again to send the body a second time, except for files created with InputFile::reader.
+    pub async fn reader(&self) -> io::Result<Box<dyn AsyncRead + Send + Unpin + '_>> {
+        let mut reader: Box<dyn AsyncRead + Send + Unpin> = Box::new(futures::io::empty());
+        for part in &self.parts {
+            reader = match part {
+                Part::Bytes(bytes) => Box::new(reader.chain(&bytes[..])),
+                Part::File(file, _) => Box::new(reader.chain(file.open().await?)),
+            };
+        }
+        Ok(reader)
+    }
+}
+
+///Encoder for multipart/form-data bodies
+pub struct Form {
+    boundary: String,
+    parts: Vec<Part>,
+    body: Vec<u8>,
+}
+
+impl Form {
+    pub fn new() -> Self {
+        //RandomState is seeded randomly for every instance
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u8(0);
+        Form {
+            boundary: format!("rohrpost-boundary-{:016x}", hasher.finish()),
+            parts: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+    pub fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+    fn part_header(&mut self, disposition: &str, content_type: Option<&str>) {
+        self.body.extend_from_slice(b"--");
+        self.body.extend_from_slice(self.boundary.as_bytes());
+        self.body
+            .extend_from_slice(b"\r\nContent-Disposition: form-data; ");
+        self.body.extend_from_slice(disposition.as_bytes());
+        self.body.extend_from_slice(b"\r\n");
+        if let Some(content_type) = content_type {
+            self.body.extend_from_slice(b"Content-Type: ");
+            self.body.extend_from_slice(content_type.as_bytes());
+            self.body.extend_from_slice(b"\r\n");
+        }
+        self.body.extend_from_slice(b"\r\n");
+    }
+    ///Adds a plain text field
+    pub fn text(&mut self, name: &str, value: &str) {
+        self.part_header(&format!("name=\"{}\"", escape(name)), None);
+        self.body.extend_from_slice(value.as_bytes());
+        self.body.extend_from_slice(b"\r\n");
+    }
+    ///Adds a file field, the file is read when the body is sent
+    pub async fn file(&mut self, name: &str, file: &InputFile) -> io::Result<()> {
+        let length = file.len().await?;
+        self.part_header(
+            &format!(
+                "name=\"{}\"; filename=\"{}\"",
+                escape(name),
+                escape(file.file_name())
+            ),
+            Some("application/octet-stream"),
+        );
+        let body = std::mem::take(&mut self.body);
+        self.parts.push(Part::Bytes(body));
+        self.parts.push(Part::File(file.clone(), length));
+        self.body.extend_from_slice(b"\r\n");
+        Ok(())
+    }
+    ///Writes the closing boundary and returns the body
+    pub fn finish(mut self) -> Body {
+        let content_type = self.content_type();
+        self.body.extend_from_slice(b"--");
+        self.body.extend_from_slice(self.boundary.as_bytes());
+        self.body.extend_from_slice(b"--\r\n");
+        self.parts.push(Part::Bytes(self.body));
+        Body {
+            parts: self.parts,
+            content_type,
+        }
+    }
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Form::new()
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace(['\r', '\n'], "")
+}
+
+///Serializes the method into a request body, which is JSON unless the method uploads an InputFile.
+pub async fn encode<M>(method: &M) -> Result<Body, Error>
+where
+    M: TelegramMethod + Serialize,
+{
+    let value = serde_json::to_value(method)?;
+    let files = method.input_files();
+    if files.is_empty() {
+        return Ok(Body::bytes(
+            serde_json::to_vec(&value)?,
+            String::from("application/json"),
+        ));
+    }
+    let mut form = Form::new();
+    if let Value::Object(fields) = value {
+        for (name, field) in fields {
+            //top level files are uploaded under the name of the field
+            if let Some(file) = files.get(&name) {
+                form.file(&name, file).await?;
+                continue;
+            }
+            match field {
+                Value::String(text) => form.text(&name, &text),
+                Value::Null => {}
+                other => form.text(&name, &other.to_string()),
+            }
+        }
+    }
+    //nested files (e.g. in InputMedia) are referenced by their attach name
+    for file in files.attachments() {
+        form.file(&file.attach_name(), file).await?;
+    }
+    Ok(form.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::Or;
+    use crate::input_file::InputFile;
+    use crate::telegram_methods::sendPhotoBuilder;
+    use futures::executor::block_on;
+
+    fn read(body: &Body) -> String {
+        let mut data = Vec::new();
+        block_on(async { body.reader().await?.read_to_end(&mut data).await }).unwrap();
+        assert_eq!(body.content_length(), Some(data.len() as u64));
+        String::from_utf8(data).unwrap()
+    }
+
+    #[test]
+    fn files_switch_to_multipart() {
+        let method = sendPhotoBuilder::default()
+            .chat_id(42)
+            .photo(Or::A(InputFile::memory("chart.png", &b"PNGDATA"[..])))
+            .caption(String::from("weekly report"))
+            .build()
+            .unwrap();
+        let body = block_on(encode(&method)).unwrap();
+        assert!(body
+            .content_type()
+            .starts_with("multipart/form-data; boundary="));
+        let body = read(&body);
+        assert!(body.contains("name=\"chat_id\"\r\n\r\n42\r\n"));
+        assert!(body.contains("name=\"photo\"; filename=\"chart.png\""));
+        assert!(body.contains("\r\n\r\nPNGDATA\r\n"));
+        assert!(body.contains("name=\"caption\"\r\n\r\nweekly report\r\n"));
+        assert!(!body.contains("attach://"));
+    }
+
+    #[test]
+    fn files_are_matched_by_identity() {
+        //a caption that looks like an attached file stays text
+        let file = InputFile::memory("chart.png", &b"PNGDATA"[..]);
+        let caption = format!("attach://{}", file.attach_name());
+        let method = sendPhotoBuilder::default()
+            .chat_id(42)
+            .photo(Or::A(file))
+            .caption(caption.clone())
+            .build()
+            .unwrap();
+        let body = read(&block_on(encode(&method)).unwrap());
+        assert!(body.contains(&format!("name=\"caption\"\r\n\r\n{}\r\n", caption)));
+        assert_eq!(body.matches("filename=").count(), 1);
+        //serializing does not depend on the encoder
+        let json = serde_json::to_string(&method).unwrap();
+        assert!(json.contains(&format!(r#""photo":"{}""#, caption)));
+    }
+
+    #[test]
+    fn nested_files_are_attached() {
+        use crate::telegram_methods::sendMediaGroupBuilder;
+        use crate::telegram_types::{InputMediaPhotoBuilder, InputMediaVideoBuilder};
+
+        let file = InputFile::memory("a.jpg", &b"JPEGDATA"[..]);
+        let name = file.attach_name();
+        let photo = InputMediaPhotoBuilder::default()
+            .media(Or::A(file.clone()))
+            .build()
+            .unwrap();
+        let video = InputMediaVideoBuilder::default()
+            .media(Or::B(String::from("existing-file-id")))
+            .build()
+            .unwrap();
+        let method = sendMediaGroupBuilder::default()
+            .chat_id(42)
+            .media(vec![photo.clone().into(), video.into(), photo.into()])
+            .build()
+            .unwrap();
+        let body = read(&block_on(encode(&method)).unwrap());
+        assert!(body.contains(&format!(
+            r#"{{"media":"attach://{}","type":"photo"}}"#,
+            name
+        )));
+        assert!(body.contains(r#"{"media":"existing-file-id","type":"video"}"#));
+        //the photo is used twice but uploaded once
+        let part = format!("name=\"{}\"; filename=\"a.jpg\"", name);
+        assert_eq!(body.matches(&part).count(), 1);
+    }
+
+    #[test]
+    fn thumbnails_are_uploaded() {
+        use crate::telegram_methods::{sendMediaGroupBuilder, sendVideoBuilder};
+        use crate::telegram_types::InputMediaVideoBuilder;
+
+        let method = sendVideoBuilder::default()
+            .chat_id(42)
+            .video(Or::A(InputFile::memory("v.mp4", &b"MP4DATA"[..])))
+            .thumb(Or::A(InputFile::memory("t.jpg", &b"THUMBDATA"[..])))
+            .build()
+            .unwrap();
+        let body = read(&block_on(encode(&method)).unwrap());
+        assert!(body.contains("name=\"thumb\"; filename=\"t.jpg\""));
+        assert!(body.contains("\r\n\r\nTHUMBDATA\r\n"));
+
+        let thumb = InputFile::memory("t.jpg", &b"THUMBDATA"[..]);
+        let name = thumb.attach_name();
+        let video = InputMediaVideoBuilder::default()
+            .media(Or::A(InputFile::memory("v.mp4", &b"MP4DATA"[..])))
+            .thumb(Or::A(thumb))
+            .build()
+            .unwrap();
+        let method = sendMediaGroupBuilder::default()
+            .chat_id(42)
+            .media(vec![video.into()])
+            .build()
+            .unwrap();
+        let body = read(&block_on(encode(&method)).unwrap());
+        assert!(body.contains(&format!(r#""thumb":"attach://{}""#, name)));
+        assert!(body.contains(&format!("name=\"{}\"; filename=\"t.jpg\"", name)));
+    }
+
+    #[test]
+    fn files_are_read_when_sent() {
+        use crate::telegram_methods::sendDocumentBuilder;
+
+        let path = std::env::temp_dir().join("rohrpost-multipart-test.txt");
+        std::fs::write(&path, b"first").unwrap();
+        let method = sendDocumentBuilder::default()
+            .chat_id(42)
+            .document(Or::A(InputFile::path(&path)))
+            .build()
+            .unwrap();
+        let body = block_on(encode(&method)).unwrap();
+        assert!(read(&body).contains("\r\n\r\nfirst\r\n"));
+        //a retry reads the file again
+        assert!(read(&body).contains("\r\n\r\nfirst\r\n"));
+        std::fs::remove_file(&path).unwrap();
+
+        //readers have no known length and can only be sent once
+        let method = sendDocumentBuilder::default()
+            .chat_id(42)
+            .document(Or::A(InputFile::reader("r.txt", &b"streamed"[..])))
+            .build()
+            .unwrap();
+        let body = block_on(encode(&method)).unwrap();
+        assert_eq!(body.content_length(), None);
+        let mut data = Vec::new();
+        block_on(async { body.reader().await?.read_to_end(&mut data).await }).unwrap();
+        assert!(String::from_utf8(data).unwrap().contains("streamed"));
+        assert!(block_on(body.reader()).is_err());
+    }
+}
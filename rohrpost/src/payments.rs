@@ -0,0 +1,85 @@
+use crate::error::Error;
+use crate::telegram_methods::answerPreCheckoutQueryBuilder;
+use crate::telegram_sender::TelegramSender;
+use crate::telegram_types::PreCheckoutQuery;
+use async_std::future;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+///Telegram cancels the checkout if a pre-checkout query is not answered within this time
+pub const PRE_CHECKOUT_WINDOW: Duration = Duration::from_secs(10);
+///Part of the window that is left for sending the answer after the order check
+const ANSWER_TIME: Duration = Duration::from_secs(3);
+
+///Answers the pre-checkout query with the outcome of check.
+/// check resolves to Err with a message for the user if the order can not be completed.
+/// received is the time the update with the query arrived, the PRE_CHECKOUT_WINDOW is counted from there.
+/// If check does not finish in time, the query is declined with timeout_message instead of letting the checkout expire.
+pub async fn answer_pre_checkout_query<F>(
+    sender: &TelegramSender,
+    query: &PreCheckoutQuery,
+    received: Instant,
+    check: F,
+    timeout_message: &str,
+) -> Result<(), Error>
+where
+    F: Future<Output = Result<(), String>>,
+{
+    let deadline = received + (PRE_CHECKOUT_WINDOW - ANSWER_TIME);
+    let check_timeout = deadline.saturating_duration_since(Instant::now());
+    let outcome = match future::timeout(check_timeout, check).await {
+        Ok(outcome) => outcome,
+        Err(_) => Err(String::from(timeout_message)),
+    };
+    let mut builder = answerPreCheckoutQueryBuilder::default();
+    builder.pre_checkout_query_id(query.id.clone());
+    match outcome {
+        Ok(()) => builder.ok(true),
+        Err(error_message) => builder.ok(false).error_message(error_message),
+    };
+    sender.call(&builder.build().unwrap()).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::telegram_sender::mock_server::{self, respond};
+    use futures::executor::block_on;
+    use serde_json::Value;
+
+    #[test]
+    fn check_that_does_not_finish_is_declined() {
+        block_on(async {
+            let (listener, config) = mock_server::bind();
+            let sender = TelegramSender::new(config);
+            let query: PreCheckoutQuery = serde_json::from_str(
+                r#"{"id":"q1","from":{"id":5,"is_bot":false,"first_name":"A"},"currency":"EUR","total_amount":100,"invoice_payload":"p"}"#,
+            )
+            .unwrap();
+            //the update arrived long ago, only a moment of the window is left for the check
+            let received =
+                Instant::now() - (PRE_CHECKOUT_WINDOW - ANSWER_TIME) + Duration::from_millis(100);
+            let server = async {
+                let mut http_stream = mock_server::accept(&listener).await;
+                let request = http_stream.read().await.unwrap();
+                assert_eq!(
+                    request.get_request().uri(),
+                    "/bot123:abc/answerPreCheckoutQuery"
+                );
+                let body: Value = serde_json::from_slice(request.get_body()).unwrap();
+                assert_eq!(body["pre_checkout_query_id"], "q1");
+                assert_eq!(body["ok"], false);
+                assert_eq!(body["error_message"], "try again");
+                respond(&mut http_stream, r#"{"ok":true,"result":true}"#).await;
+            };
+            let client = async {
+                let check = futures::future::pending();
+                answer_pre_checkout_query(&sender, &query, received, check, "try again")
+                    .await
+                    .unwrap();
+            };
+            futures::join!(server, client);
+        });
+    }
+}
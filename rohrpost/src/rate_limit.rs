@@ -0,0 +1,277 @@
+use crate::chat_id::ChatId;
+use async_std::task;
+use serde::ser::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+///Limits for outgoing messages, the defaults are the flood limits telegram documents.
+/// Only methods that send a message to a chat are limited, see TelegramMethod::RATE_LIMITED.
+#[derive(Clone, Debug)]
+pub struct RateLimits {
+    global: (usize, Duration),
+    private_chat: (usize, Duration),
+    group: (usize, Duration),
+}
+
+impl RateLimits {
+    ///30 messages per second overall, 1 per second in a private chat and 20 per minute in a group or channel
+    pub fn new() -> Self {
+        RateLimits {
+            global: (30, Duration::from_secs(1)),
+            private_chat: (1, Duration::from_secs(1)),
+            group: (20, Duration::from_secs(60)),
+        }
+    }
+    ///At most limit messages per period over all chats.
+    /// They are spread evenly over the period instead of being sent in a burst.
+    pub fn global(mut self, limit: usize, period: Duration) -> Self {
+        self.global = (limit.max(1), period);
+        self
+    }
+    ///At most limit messages per period to the same private chat
+    pub fn private_chat(mut self, limit: usize, period: Duration) -> Self {
+        self.private_chat = (limit.max(1), period);
+        self
+    }
+    ///At most limit messages per period to the same group, supergroup or channel
+    pub fn group(mut self, limit: usize, period: Duration) -> Self {
+        self.group = (limit.max(1), period);
+        self
+    }
+}
+
+impl Default for RateLimits {
+    fn default() -> Self {
+        RateLimits::new()
+    }
+}
+
+///Times of the last messages to a chat
+struct Window {
+    limit: usize,
+    period: Duration,
+    times: VecDeque<Instant>,
+}
+
+impl Window {
+    ///Earliest time at or after not_before for the next message, never earlier than the last one
+    fn earliest(&self, not_before: Instant) -> Instant {
+        let mut at = not_before;
+        if let Some(&last) = self.times.back() {
+            at = at.max(last);
+        }
+        if self.times.len() >= self.limit {
+            at = at.max(self.times[self.times.len() - self.limit] + self.period);
+        }
+        at
+    }
+    fn push(&mut self, at: Instant) {
+        self.times.push_back(at);
+        while self.times.len() > self.limit {
+            self.times.pop_front();
+        }
+    }
+    fn is_idle(&self, now: Instant) -> bool {
+        match self.times.back() {
+            Some(&last) => last + self.period <= now,
+            None => true,
+        }
+    }
+}
+
+struct State {
+    limits: RateLimits,
+    chats: HashMap<ChatId, Window>,
+    //sorted send times of all messages that are waiting or were sent recently
+    global: Vec<Instant>,
+    paused_until: Option<Instant>,
+    //numeric ids of @usernames, so that both refer to the same window
+    usernames: HashMap<String, i64>,
+}
+
+impl State {
+    fn reserve(&mut self, chat_id: &ChatId, now: Instant) -> Instant {
+        let not_before = match self.paused_until {
+            Some(paused_until) if paused_until > now => paused_until,
+            _ => now,
+        };
+        let (limit, period) = match chat_id {
+            //user ids are positive, groups and channels have negative ids or a @username
+            ChatId::Id(id) if *id > 0 => self.limits.private_chat,
+            _ => self.limits.group,
+        };
+        self.chats.retain(|_, window| !window.is_idle(now));
+        let window = self.chats.entry(chat_id.clone()).or_insert_with(|| Window {
+            limit,
+            period,
+            times: VecDeque::new(),
+        });
+        let at = window.earliest(not_before);
+        let at = self.reserve_global(at, now);
+        self.chats.get_mut(chat_id).unwrap().push(at);
+        at
+    }
+    ///Finds the earliest slot at or after not_before that keeps the global spacing to all other slots.
+    /// Messages of a chat that has to wait do not hold back messages of other chats.
+    fn reserve_global(&mut self, not_before: Instant, now: Instant) -> Instant {
+        let (limit, period) = self.limits.global;
+        let spacing = period / limit as u32;
+        self.global.retain(|&at| at + spacing > now);
+        let mut at = not_before;
+        let mut index = 0;
+        for (i, &slot) in self.global.iter().enumerate() {
+            if slot + spacing <= at {
+                index = i + 1;
+                continue;
+            }
+            if at + spacing <= slot {
+                break;
+            }
+            at = slot + spacing;
+            index = i + 1;
+        }
+        self.global.insert(index, at);
+        at
+    }
+}
+
+///Delays outgoing messages so that they stay within the RateLimits.
+/// Messages to the same chat are sent in the order they were queued.
+pub struct RateLimiter {
+    state: Mutex<State>,
+}
+
+impl RateLimiter {
+    pub fn new(limits: RateLimits) -> Self {
+        RateLimiter {
+            state: Mutex::new(State {
+                limits,
+                chats: HashMap::new(),
+                global: Vec::new(),
+                paused_until: None,
+                usernames: HashMap::new(),
+            }),
+        }
+    }
+    ///The numeric id of a @username that was resolved before
+    pub(crate) fn resolved(&self, username: &str) -> Option<i64> {
+        let state = self.state.lock().unwrap();
+        state.usernames.get(&username.to_lowercase()).copied()
+    }
+    pub(crate) fn resolve(&self, username: &str, id: i64) {
+        let mut state = self.state.lock().unwrap();
+        state.usernames.insert(username.to_lowercase(), id);
+    }
+    ///Waits until a message to the chat may be sent
+    pub async fn acquire(&self, chat_id: &ChatId) {
+        let at = self.state.lock().unwrap().reserve(chat_id, Instant::now());
+        sleep_until(at).await;
+        //a flood error may have paused sending while this message was waiting
+        loop {
+            let paused_until = self.state.lock().unwrap().paused_until;
+            match paused_until {
+                Some(paused_until) if paused_until > Instant::now() => {
+                    sleep_until(paused_until).await
+                }
+                _ => break,
+            }
+        }
+    }
+    ///Stops sending for the given time, called when telegram answers with retry_after anyway
+    pub fn pause(&self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut state = self.state.lock().unwrap();
+        let later = match state.paused_until {
+            Some(paused_until) => paused_until < until,
+            None => true,
+        };
+        if later {
+            state.paused_until = Some(until);
+        }
+    }
+}
+
+async fn sleep_until(at: Instant) {
+    let now = Instant::now();
+    if at > now {
+        task::sleep(at - now).await;
+    }
+}
+
+///The chat_id field of the serialized method
+pub(crate) fn chat_id_of<M>(method: &M) -> Option<ChatId>
+where
+    M: Serialize,
+{
+    match serde_json::to_value(method).ok()?.get("chat_id")? {
+        Value::Number(id) => id.as_i64().map(ChatId::Id),
+        Value::String(id) => id.parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chats_are_limited_separately() {
+        let now = Instant::now();
+        let second = Duration::from_secs(1);
+        let mut state = State {
+            limits: RateLimits::new().global(10, second),
+            chats: HashMap::new(),
+            global: Vec::new(),
+            paused_until: None,
+            usernames: HashMap::new(),
+        };
+        let user = ChatId::Id(5);
+        let group = ChatId::Id(-100);
+        assert_eq!(state.reserve(&user, now), now);
+        //the second message to the user waits a second
+        assert_eq!(state.reserve(&user, now), now + second);
+        //while the group is served in between, spaced by the global limit
+        let spacing = second / 10;
+        assert_eq!(state.reserve(&group, now), now + spacing);
+        assert_eq!(state.reserve(&group, now), now + spacing * 2);
+        for _ in 2..20 {
+            state.reserve(&group, now);
+        }
+        //the 21st message to the group waits for the minute to pass
+        let at = state.reserve(&group, now);
+        assert_eq!(at, now + spacing + Duration::from_secs(60));
+    }
+
+    #[test]
+    fn chat_id_is_read_from_the_method() {
+        let method = crate::telegram_methods::sendMessageBuilder::default()
+            .chat_id("@channel")
+            .text(String::from("hi"))
+            .build()
+            .unwrap();
+        assert_eq!(
+            chat_id_of(&method),
+            Some(ChatId::Username(String::from("@channel")))
+        );
+        assert_eq!(chat_id_of(&crate::telegram_methods::getMe {}), None);
+    }
+
+    #[test]
+    fn usernames_are_resolved_case_insensitively() {
+        use crate::telegram_methods::{deleteMessage, getChat, sendMessage};
+
+        let limiter = RateLimiter::new(RateLimits::new());
+        assert_eq!(limiter.resolved("@channel"), None);
+        limiter.resolve("@Channel", -100);
+        assert_eq!(limiter.resolved("@channel"), Some(-100));
+        //only methods that send messages are limited
+        assert!(rate_limited::<sendMessage>());
+        assert!(!rate_limited::<getChat>() && !rate_limited::<deleteMessage>());
+    }
+
+    fn rate_limited<M: crate::telegram_methods::TelegramMethod>() -> bool {
+        M::RATE_LIMITED
+    }
+}
@@ -0,0 +1,198 @@
+use crate::error::Error;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::io;
+use std::time::Duration;
+
+///A failed call that is about to be sent again, passed to the on_retry hook
+pub struct Retry<'a> {
+    pub method_name: &'static str,
+    ///1 for the first retry
+    pub attempt: usize,
+    pub error: &'a Error,
+    ///Time until the method is sent again
+    pub delay: Duration,
+}
+
+type RetryHook = Box<dyn Fn(&Retry<'_>) + Send + Sync>;
+
+///When TelegramSender::call sends a method again after a transient failure.
+/// Connection errors, 5xx responses and flood errors (429) are retried with exponential backoff and jitter,
+/// flood errors wait for retry_after instead.
+/// Methods that are not idempotent, like sendMessage, are only retried if the request did not reach telegram.
+pub struct RetryPolicy {
+    max_retries: usize,
+    initial_delay: Duration,
+    max_delay: Duration,
+    on_retry: Option<RetryHook>,
+}
+
+impl RetryPolicy {
+    ///3 retries, the delay starts at half a second and doubles up to 30 seconds
+    pub fn new() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            on_retry: None,
+        }
+    }
+    ///Every error is returned right away
+    pub fn none() -> Self {
+        RetryPolicy::new().max_retries(0)
+    }
+    pub fn max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+    ///Delay before the first retry, it doubles with every further retry
+    pub fn initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+    ///Called before every retry, for example to log it
+    pub fn on_retry<F>(mut self, on_retry: F) -> Self
+    where
+        F: Fn(&Retry<'_>) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(Box::new(on_retry));
+        self
+    }
+    ///Returns how long to wait before sending the method again, or None if the error should be returned.
+    /// sent tells whether the request may have reached telegram.
+    pub(crate) fn retry(
+        &self,
+        method_name: &'static str,
+        idempotent: bool,
+        attempt: usize,
+        error: &Error,
+        sent: bool,
+    ) -> Option<Duration> {
+        if attempt > self.max_retries || !is_transient(error, idempotent || !sent) {
+            return None;
+        }
+        let delay = match error {
+            Error::Api {
+                retry_after: Some(retry_after),
+                ..
+            } => Duration::from_secs(*retry_after.max(&0) as u64),
+            _ => self.backoff(attempt),
+        };
+        if let Some(on_retry) = &self.on_retry {
+            on_retry(&Retry {
+                method_name,
+                attempt,
+                error,
+                delay,
+            });
+        }
+        Some(delay)
+    }
+    ///Exponential backoff, the second half of the delay is random so that clients do not retry in lockstep
+    fn backoff(&self, attempt: usize) -> Duration {
+        let factor = 1u32 << (attempt - 1).min(16);
+        let delay = self
+            .initial_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        //RandomState is seeded randomly for every instance
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_usize(attempt);
+        let jitter = (hasher.finish() % 1000) as u32;
+        delay / 2 + delay / 2 * jitter / 1000
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new()
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_retries", &self.max_retries)
+            .field("initial_delay", &self.initial_delay)
+            .field("max_delay", &self.max_delay)
+            .finish()
+    }
+}
+
+///Flood errors are always safe to retry, telegram did not execute the method.
+/// Connection errors and server errors only if repeating the method does no harm.
+fn is_transient(error: &Error, may_repeat: bool) -> bool {
+    match error {
+        Error::Api {
+            error_code: 429, ..
+        } => true,
+        Error::Api { error_code, .. } => *error_code >= 500 && may_repeat,
+        Error::IO(err) => {
+            may_repeat
+                && matches!(
+                    err.kind(),
+                    io::ErrorKind::ConnectionRefused
+                        | io::ErrorKind::ConnectionReset
+                        | io::ErrorKind::ConnectionAborted
+                        | io::ErrorKind::BrokenPipe
+                        | io::ErrorKind::UnexpectedEof
+                        | io::ErrorKind::TimedOut
+                        | io::ErrorKind::Interrupted
+                )
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn api_error(error_code: i64, retry_after: Option<i64>) -> Error {
+        Error::Api {
+            error_code,
+            description: String::new(),
+            retry_after,
+            migrate_to_chat_id: None,
+        }
+    }
+
+    #[test]
+    fn only_transient_errors_are_retried() {
+        let retries = Arc::new(AtomicUsize::new(0));
+        let counter = retries.clone();
+        let policy = RetryPolicy::new()
+            .initial_delay(Duration::from_secs(1))
+            .on_retry(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        let reset = Error::IO(io::Error::from(io::ErrorKind::ConnectionReset));
+        //a reset while connecting can be retried even for sendMessage
+        let delay = policy
+            .retry("sendMessage", false, 1, &reset, false)
+            .unwrap();
+        assert!(delay >= Duration::from_millis(500) && delay <= Duration::from_secs(1));
+        //but not after the message was written
+        assert_eq!(policy.retry("sendMessage", false, 1, &reset, true), None);
+        let delay = policy.retry("getChat", true, 3, &api_error(502, None), true);
+        assert!(delay.unwrap() >= Duration::from_secs(2));
+        assert_eq!(
+            policy.retry("sendMessage", false, 1, &api_error(429, Some(7)), true),
+            Some(Duration::from_secs(7))
+        );
+        assert_eq!(policy.retry("getChat", true, 4, &reset, false), None);
+        assert_eq!(
+            policy.retry("getChat", true, 1, &api_error(400, None), false),
+            None
+        );
+        assert_eq!(retries.load(Ordering::SeqCst), 3);
+    }
+}
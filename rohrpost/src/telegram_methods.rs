@@ -1,15 +1,93 @@
 use crate::helpers::Or;
+use crate::input_file::InputFiles;
 use crate::telegram_types::*;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 type Integer = i64;
 type Boolean = bool;
 type True = bool;
 type Float = f64;
-//unsupported types
-type InputFile = ();
+///A ChatId field of a builder, Option<ChatId> for required and Option<Option<ChatId>> for optional fields
+trait ChatIdField {
+    fn chat_id(&self) -> Option<&ChatId>;
+}
+
+impl ChatIdField for Option<ChatId> {
+    fn chat_id(&self) -> Option<&ChatId> {
+        self.as_ref()
+    }
+}
+
+impl ChatIdField for Option<Option<ChatId>> {
+    fn chat_id(&self) -> Option<&ChatId> {
+        self.as_ref().and_then(Option::as_ref)
+    }
+}
+
+///Lets build fail for usernames that were passed to the builder as an invalid string
+macro_rules! validate_chat_ids {
+    ($($builder:ident { $($field:ident),* })*) => {
+        $(
+            impl $builder {
+                fn validate(&self) -> Result<(), String> {
+                    $(
+                        if let Some(chat_id) = ChatIdField::chat_id(&self.$field) {
+                            chat_id.check().map_err(|err| err.to_string())?;
+                        }
+                    )*
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
 ///Marker Trait for TelegramMethod structs
 pub trait TelegramMethod {
     const method_name: &'static str;
+    ///False for methods that must not be sent twice, like sendMessage.
+    /// They are only retried if the request did not reach telegram.
+    const IDEMPOTENT: bool = true;
+    ///True for methods that send a message to a chat, they wait for the rate limiter of the TelegramSender
+    const RATE_LIMITED: bool = false;
+    ///Type of the result field in a successful response
+    type Response: DeserializeOwned;
+    ///The InputFiles the method uploads, none by default
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default()
+    }
+}
+///
+///Use this method to receive incoming updates using long polling (wiki). An Array of Update objects is returned.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct getUpdates {
+    ///Identifier of the first update to be returned. Must be greater by one than the highest among the identifiers of previously received updates. By default, updates starting with the earliest unconfirmed update are returned. An update is considered confirmed as soon as getUpdates is called with an offset higher than its update_id. The negative offset can be specified to retrieve updates starting from -offset update from the end of the updates queue. All previous updates will forgotten.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<Integer>,
+    ///Limits the number of updates to be retrieved. Values between 1—100 are accepted. Defaults to 100.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<Integer>,
+    ///Timeout in seconds for long polling. Defaults to 0, i.e. usual short polling. Should be positive, short polling should be used for testing purposes only.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<Integer>,
+    ///List the types of updates you want your bot to receive. For example, specify [“message”, “edited_channel_post”, “callback_query”] to only receive updates of these types. See Update for a complete list of available update types. Specify an empty list to receive all updates regardless of type (default). If not specified, the previous setting will be used.
+    ///
+    ///Please note that this parameter doesn't affect updates created before the call to the getUpdates, so unwanted updates may be received for a short period of time.
+    ///
+    ///Notes
+    ///1. This method will not work if an outgoing webhook is set up.
+    ///2. In order to avoid getting duplicate updates, recalculate offset after each server response.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_updates: Option<Vec<String>>,
+}
+impl TelegramMethod for getUpdates {
+    const method_name: &'static str = "getUpdates";
+    type Response = Vec<Update>;
 }
 ///
 ///Use this method to specify a url and receive incoming updates via an outgoing webhook. Whenever there is an update for the bot, we will send an HTTPS POST request to the specified url, containing a JSON-serialized Update. In case of an unsuccessful request, we will give up after a reasonable amount of attempts. Returns True on success.
@@ -42,9 +120,17 @@ pub struct setWebhook {
     ///
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_updates: Option<Vec<String>>,
+    ///A secret token to be sent in a header “X-Telegram-Bot-Api-Secret-Token” in every webhook request, 1-256 characters. Only characters A-Z, a-z, 0-9, _ and - are allowed. The header is useful to ensure that the request comes from a webhook set by you.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_token: Option<String>,
 }
 impl TelegramMethod for setWebhook {
     const method_name: &'static str = "setWebhook";
+    type Response = True;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default().field("certificate", &self.certificate)
+    }
 }
 ///
 ///Use this method to remove webhook integration if you decide to switch back to getUpdates. Returns True on success. Requires no parameters.
@@ -53,6 +139,7 @@ impl TelegramMethod for setWebhook {
 pub struct deleteWebhook {}
 impl TelegramMethod for deleteWebhook {
     const method_name: &'static str = "deleteWebhook";
+    type Response = True;
 }
 ///
 ///Use this method to get current webhook status. Requires no parameters. On success, returns a WebhookInfo object. If the bot is using getUpdates, will return an object with the url field empty.
@@ -61,39 +148,32 @@ impl TelegramMethod for deleteWebhook {
 pub struct getWebhookInfo {}
 impl TelegramMethod for getWebhookInfo {
     const method_name: &'static str = "getWebhookInfo";
+    type Response = crate::telegram_types::WebhookInfo;
 }
 ///
-///Contains information about the current status of a webhook.
-///Field 	Type 	Description
-///url 	String 	Webhook URL, may be empty if webhook is not set up
-///has_custom_certificate 	Boolean 	True, if a custom certificate was provided for webhook certificate checks
-///pending_update_count 	Integer 	Number of updates awaiting delivery
-///last_error_date 	Integer 	Optional. Unix time for the most recent error that happened when trying to deliver an update via webhook
-///last_error_message 	String 	Optional. Error message in human-readable format for the most recent error that happened when trying to deliver an update via webhook
-///max_connections 	Integer 	Optional. Maximum allowed number of simultaneous HTTPS connections to the webhook for update delivery
-///allowed_updates 	Array of String 	Optional. A list of update types the bot is subscribed to. Defaults to all update types
-///getMe
-///
 ///A simple method for testing your bot's auth token. Requires no parameters. Returns basic information about the bot in form of a User object.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
-pub struct WebhookInfo {}
-impl TelegramMethod for WebhookInfo {
-    const method_name: &'static str = "WebhookInfo";
+pub struct getMe {}
+impl TelegramMethod for getMe {
+    const method_name: &'static str = "getMe";
+    type Response = User;
 }
 ///
 ///Use this method to send text messages. On success, the sent Message is returned.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendMessage {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Text of the message to be sent
     pub text: String,
     ///Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in your bot's message.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Disables link previews for links in this message
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -129,12 +209,13 @@ pub struct sendMessage {
     ///[inline mention of a user](tg://user?id=123456789)
     ///`inline fixed-width code`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<
-        Or<InlineKeyboardMarkup, Or<ReplyKeyboardMarkup, Or<ReplyKeyboardRemove, ForceReply>>>,
-    >,
+    pub reply_markup: Option<ReplyMarkup>,
 }
 impl TelegramMethod for sendMessage {
     const method_name: &'static str = "sendMessage";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
 }
 ///pre-formatted fixed-width code block
 ///```
@@ -163,11 +244,14 @@ impl TelegramMethod for sendMessage {
 ///Use this method to forward messages of any kind. On success, the sent Message is returned.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct forwardMessage {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Unique identifier for the chat where the original message was sent (or channel username in the format @channelusername)
-    pub from_chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub from_chat_id: ChatId,
     ///Sends the message silently. Users will receive a notification with no sound.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -177,14 +261,19 @@ pub struct forwardMessage {
 }
 impl TelegramMethod for forwardMessage {
     const method_name: &'static str = "forwardMessage";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
 }
 ///
 ///Use this method to send photos. On success, the sent Message is returned.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendPhoto {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Photo to send. Pass a file_id as String to send a photo that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a photo from the Internet, or upload a new photo using multipart/form-data. More info on Sending Files »
     pub photo: Or<InputFile, String>,
     ///Photo caption (may also be used when resending photos by file_id), 0-1024 characters
@@ -194,7 +283,7 @@ pub struct sendPhoto {
     ///Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Sends the message silently. Users will receive a notification with no sound.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -206,12 +295,16 @@ pub struct sendPhoto {
     ///Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<
-        Or<InlineKeyboardMarkup, Or<ReplyKeyboardMarkup, Or<ReplyKeyboardRemove, ForceReply>>>,
-    >,
+    pub reply_markup: Option<ReplyMarkup>,
 }
 impl TelegramMethod for sendPhoto {
     const method_name: &'static str = "sendPhoto";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default().field("photo", &self.photo)
+    }
 }
 ///
 ///Use this method to send audio files, if you want Telegram clients to display them in the music player. Your audio must be in the .MP3 or .M4A format. On success, the sent Message is returned. Bots can currently send audio files of up to 50 MB in size, this limit may be changed in the future.
@@ -219,9 +312,11 @@ impl TelegramMethod for sendPhoto {
 ///For sending voice messages, use the sendVoice method instead.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendAudio {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Audio file to send. Pass a file_id as String to send an audio file that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get an audio file from the Internet, or upload a new one using multipart/form-data. More info on Sending Files »
     pub audio: Or<InputFile, String>,
     ///Audio caption, 0-1024 characters
@@ -231,7 +326,7 @@ pub struct sendAudio {
     ///Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Duration of the audio in seconds
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -259,20 +354,28 @@ pub struct sendAudio {
     ///Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<
-        Or<InlineKeyboardMarkup, Or<ReplyKeyboardMarkup, Or<ReplyKeyboardRemove, ForceReply>>>,
-    >,
+    pub reply_markup: Option<ReplyMarkup>,
 }
 impl TelegramMethod for sendAudio {
     const method_name: &'static str = "sendAudio";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default()
+            .field("audio", &self.audio)
+            .field("thumb", &self.thumb)
+    }
 }
 ///
 ///Use this method to send general files. On success, the sent Message is returned. Bots can currently send files of any type of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendDocument {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///File to send. Pass a file_id as String to send a file that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a file from the Internet, or upload a new one using multipart/form-data. More info on Sending Files »
     pub document: Or<InputFile, String>,
     ///Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail‘s width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can’t be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More info on Sending Files »
@@ -286,7 +389,7 @@ pub struct sendDocument {
     ///Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Sends the message silently. Users will receive a notification with no sound.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -298,20 +401,28 @@ pub struct sendDocument {
     ///Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<
-        Or<InlineKeyboardMarkup, Or<ReplyKeyboardMarkup, Or<ReplyKeyboardRemove, ForceReply>>>,
-    >,
+    pub reply_markup: Option<ReplyMarkup>,
 }
 impl TelegramMethod for sendDocument {
     const method_name: &'static str = "sendDocument";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default()
+            .field("document", &self.document)
+            .field("thumb", &self.thumb)
+    }
 }
 ///
 ///Use this method to send video files, Telegram clients support mp4 videos (other formats may be sent as Document). On success, the sent Message is returned. Bots can currently send video files of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendVideo {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Video to send. Pass a file_id as String to send a video that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a video from the Internet, or upload a new video using multipart/form-data. More info on Sending Files »
     pub video: Or<InputFile, String>,
     ///Duration of sent video in seconds
@@ -337,7 +448,7 @@ pub struct sendVideo {
     ///Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Pass True, if the uploaded video is suitable for streaming
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -353,20 +464,28 @@ pub struct sendVideo {
     ///Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<
-        Or<InlineKeyboardMarkup, Or<ReplyKeyboardMarkup, Or<ReplyKeyboardRemove, ForceReply>>>,
-    >,
+    pub reply_markup: Option<ReplyMarkup>,
 }
 impl TelegramMethod for sendVideo {
     const method_name: &'static str = "sendVideo";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default()
+            .field("video", &self.video)
+            .field("thumb", &self.thumb)
+    }
 }
 ///
 ///Use this method to send animation files (GIF or H.264/MPEG-4 AVC video without sound). On success, the sent Message is returned. Bots can currently send animation files of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendAnimation {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Animation to send. Pass a file_id as String to send an animation that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get an animation from the Internet, or upload a new animation using multipart/form-data. More info on Sending Files »
     pub animation: Or<InputFile, String>,
     ///Duration of sent animation in seconds
@@ -392,7 +511,7 @@ pub struct sendAnimation {
     ///Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Sends the message silently. Users will receive a notification with no sound.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -404,20 +523,28 @@ pub struct sendAnimation {
     ///Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<
-        Or<InlineKeyboardMarkup, Or<ReplyKeyboardMarkup, Or<ReplyKeyboardRemove, ForceReply>>>,
-    >,
+    pub reply_markup: Option<ReplyMarkup>,
 }
 impl TelegramMethod for sendAnimation {
     const method_name: &'static str = "sendAnimation";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default()
+            .field("animation", &self.animation)
+            .field("thumb", &self.thumb)
+    }
 }
 ///
 ///Use this method to send audio files, if you want Telegram clients to display the file as a playable voice message. For this to work, your audio must be in an .ogg file encoded with OPUS (other formats may be sent as Audio or Document). On success, the sent Message is returned. Bots can currently send voice messages of up to 50 MB in size, this limit may be changed in the future.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendVoice {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Audio file to send. Pass a file_id as String to send a file that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a file from the Internet, or upload a new one using multipart/form-data. More info on Sending Files »
     pub voice: Or<InputFile, String>,
     ///Voice message caption, 0-1024 characters
@@ -427,7 +554,7 @@ pub struct sendVoice {
     ///Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Duration of the voice message in seconds
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -443,20 +570,26 @@ pub struct sendVoice {
     ///Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<
-        Or<InlineKeyboardMarkup, Or<ReplyKeyboardMarkup, Or<ReplyKeyboardRemove, ForceReply>>>,
-    >,
+    pub reply_markup: Option<ReplyMarkup>,
 }
 impl TelegramMethod for sendVoice {
     const method_name: &'static str = "sendVoice";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default().field("voice", &self.voice)
+    }
 }
 ///
 ///As of v.4.0, Telegram clients support rounded square mp4 videos of up to 1 minute long. Use this method to send video messages. On success, the sent Message is returned.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendVideoNote {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Video note to send. Pass a file_id as String to send a video note that exists on the Telegram servers (recommended) or upload a new video using multipart/form-data. More info on Sending Files ». Sending video notes by a URL is currently unsupported
     pub video_note: Or<InputFile, String>,
     ///Duration of sent video in seconds
@@ -482,22 +615,30 @@ pub struct sendVideoNote {
     ///Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<
-        Or<InlineKeyboardMarkup, Or<ReplyKeyboardMarkup, Or<ReplyKeyboardRemove, ForceReply>>>,
-    >,
+    pub reply_markup: Option<ReplyMarkup>,
 }
 impl TelegramMethod for sendVideoNote {
     const method_name: &'static str = "sendVideoNote";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default()
+            .field("video_note", &self.video_note)
+            .field("thumb", &self.thumb)
+    }
 }
 ///
 ///Use this method to send a group of photos or videos as an album. On success, an array of the sent Messages is returned.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendMediaGroup {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
-    //    ///A JSON-serialized array describing photos and videos to be sent, must include 2–10 items
-    //    pub media: Vec,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
+    ///A JSON-serialized array describing photos and videos to be sent, must include 2–10 items
+    pub media: Vec<InputMedia>,
     ///Sends the messages silently. Users will receive a notification with no sound.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -509,14 +650,22 @@ pub struct sendMediaGroup {
 }
 impl TelegramMethod for sendMediaGroup {
     const method_name: &'static str = "sendMediaGroup";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Vec<Message>;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default().attached(&self.media)
+    }
 }
 ///
 ///Use this method to send point on the map. On success, the sent Message is returned.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendLocation {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Latitude of the location
     pub latitude: Float,
     ///Longitude of the location
@@ -536,22 +685,24 @@ pub struct sendLocation {
     ///Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<
-        Or<InlineKeyboardMarkup, Or<ReplyKeyboardMarkup, Or<ReplyKeyboardRemove, ForceReply>>>,
-    >,
+    pub reply_markup: Option<ReplyMarkup>,
 }
 impl TelegramMethod for sendLocation {
     const method_name: &'static str = "sendLocation";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
 }
 ///
 ///Use this method to edit live location messages. A location can be edited until its live_period expires or editing is explicitly disabled by a call to stopMessageLiveLocation. On success, if the edited message was sent by the bot, the edited Message is returned, otherwise True is returned.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct editMessageLiveLocation {
     ///Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    #[builder(default)]
+    #[builder(default, setter(into, strip_option))]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<Or<Integer, String>>,
+    pub chat_id: Option<ChatId>,
     ///Required if inline_message_id is not specified. Identifier of the message to edit
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -571,16 +722,18 @@ pub struct editMessageLiveLocation {
 }
 impl TelegramMethod for editMessageLiveLocation {
     const method_name: &'static str = "editMessageLiveLocation";
+    type Response = Or<Message, True>;
 }
 ///
 ///Use this method to stop updating a live location message before live_period expires. On success, if the message was sent by the bot, the sent Message is returned, otherwise True is returned.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct stopMessageLiveLocation {
     ///Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    #[builder(default)]
+    #[builder(default, setter(into, strip_option))]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub chat_id: Option<Or<Integer, String>>,
+    pub chat_id: Option<ChatId>,
     ///Required if inline_message_id is not specified. Identifier of the message with live location to stop
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -596,14 +749,17 @@ pub struct stopMessageLiveLocation {
 }
 impl TelegramMethod for stopMessageLiveLocation {
     const method_name: &'static str = "stopMessageLiveLocation";
+    type Response = Or<Message, True>;
 }
 ///
 ///Use this method to send information about a venue. On success, the sent Message is returned.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendVenue {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Latitude of the venue
     pub latitude: Float,
     ///Longitude of the venue
@@ -631,20 +787,23 @@ pub struct sendVenue {
     ///Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<
-        Or<InlineKeyboardMarkup, Or<ReplyKeyboardMarkup, Or<ReplyKeyboardRemove, ForceReply>>>,
-    >,
+    pub reply_markup: Option<ReplyMarkup>,
 }
 impl TelegramMethod for sendVenue {
     const method_name: &'static str = "sendVenue";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
 }
 ///
 ///Use this method to send phone contacts. On success, the sent Message is returned.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendContact {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Contact's phone number
     pub phone_number: String,
     ///Contact's first name
@@ -668,20 +827,23 @@ pub struct sendContact {
     ///Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove keyboard or to force a reply from the user.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<
-        Or<InlineKeyboardMarkup, Or<ReplyKeyboardMarkup, Or<ReplyKeyboardRemove, ForceReply>>>,
-    >,
+    pub reply_markup: Option<ReplyMarkup>,
 }
 impl TelegramMethod for sendContact {
     const method_name: &'static str = "sendContact";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
 }
 ///
 ///Use this method to send a native poll. A native poll can't be sent to a private chat. On success, the sent Message is returned.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendPoll {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername). A native poll can't be sent to a private chat.
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Poll question, 1-255 characters
     pub question: String,
     ///List of answer options, 2-10 strings 1-100 characters each
@@ -697,12 +859,13 @@ pub struct sendPoll {
     ///Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub reply_markup: Option<
-        Or<InlineKeyboardMarkup, Or<ReplyKeyboardMarkup, Or<ReplyKeyboardRemove, ForceReply>>>,
-    >,
+    pub reply_markup: Option<ReplyMarkup>,
 }
 impl TelegramMethod for sendPoll {
     const method_name: &'static str = "sendPoll";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
 }
 ///
 ///Use this method when you need to tell the user that something is happening on the bot's side. The status is set for 5 seconds or less (when a message arrives from your bot, Telegram clients clear its typing status). Returns True on success.
@@ -712,14 +875,17 @@ impl TelegramMethod for sendPoll {
 ///We only recommend using this method when a response from the bot will take a noticeable amount of time to arrive.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct sendChatAction {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Type of action to broadcast. Choose one, depending on what the user is about to receive: typing for text messages, upload_photo for photos, record_video or upload_video for videos, record_audio or upload_audio for audio files, upload_document for general files, find_location for location data, record_video_note or upload_video_note for video notes.
-    pub action: String,
+    pub action: ChatAction,
 }
 impl TelegramMethod for sendChatAction {
     const method_name: &'static str = "sendChatAction";
+    type Response = True;
 }
 ///
 ///Use this method to get a list of profile pictures for a user. Returns a UserProfilePhotos object.
@@ -739,6 +905,7 @@ pub struct getUserProfilePhotos {
 }
 impl TelegramMethod for getUserProfilePhotos {
     const method_name: &'static str = "getUserProfilePhotos";
+    type Response = UserProfilePhotos;
 }
 ///
 ///Use this method to get basic info about a file and prepare it for downloading. For the moment, bots can download files of up to 20MB in size. On success, a File object is returned. The file can then be downloaded via the link https://api.telegram.org/file/bot<token>/<file_path>, where <file_path> is taken from the response. It is guaranteed that the link will be valid for at least 1 hour. When the link expires, a new one can be requested by calling getFile again.
@@ -752,14 +919,17 @@ pub struct getFile {
 }
 impl TelegramMethod for getFile {
     const method_name: &'static str = "getFile";
+    type Response = File;
 }
 ///
 ///Use this method to kick a user from a group, a supergroup or a channel. In the case of supergroups and channels, the user will not be able to return to the group on their own using invite links, etc., unless unbanned first. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct kickChatMember {
     ///Unique identifier for the target group or username of the target supergroup or channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Unique identifier of the target user
     pub user_id: Integer,
     ///Date when the user will be unbanned, unix time. If user is banned for more than 366 days or less than 30 seconds from the current time they are considered to be banned forever
@@ -769,27 +939,33 @@ pub struct kickChatMember {
 }
 impl TelegramMethod for kickChatMember {
     const method_name: &'static str = "kickChatMember";
+    type Response = True;
 }
 ///
 ///Use this method to unban a previously kicked user in a supergroup or channel. The user will not return to the group or channel automatically, but will be able to join via link, etc. The bot must be an administrator for this to work. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct unbanChatMember {
     ///Unique identifier for the target group or username of the target supergroup or channel (in the format @username)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Unique identifier of the target user
     pub user_id: Integer,
 }
 impl TelegramMethod for unbanChatMember {
     const method_name: &'static str = "unbanChatMember";
+    type Response = True;
 }
 ///
 ///Use this method to restrict a user in a supergroup. The bot must be an administrator in the supergroup for this to work and must have the appropriate admin rights. Pass True for all permissions to lift restrictions from a user. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct restrictChatMember {
     ///Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Unique identifier of the target user
     pub user_id: Integer,
     ///New user permissions
@@ -801,14 +977,17 @@ pub struct restrictChatMember {
 }
 impl TelegramMethod for restrictChatMember {
     const method_name: &'static str = "restrictChatMember";
+    type Response = True;
 }
 ///
 ///Use this method to promote or demote a user in a supergroup or a channel. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Pass False for all boolean parameters to demote a user. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct promoteChatMember {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Unique identifier of the target user
     pub user_id: Integer,
     ///Pass True, if the administrator can change chat title, photo and other settings
@@ -846,78 +1025,100 @@ pub struct promoteChatMember {
 }
 impl TelegramMethod for promoteChatMember {
     const method_name: &'static str = "promoteChatMember";
+    type Response = True;
 }
 ///
 ///Use this method to set default chat permissions for all members. The bot must be an administrator in the group or a supergroup for this to work and must have the can_restrict_members admin rights. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct setChatPermissions {
     ///Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///New default chat permissions
     pub permissions: ChatPermissions,
 }
 impl TelegramMethod for setChatPermissions {
     const method_name: &'static str = "setChatPermissions";
+    type Response = True;
 }
 ///
 ///Use this method to generate a new invite link for a chat; any previously generated link is revoked. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns the new invite link as String on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct exportChatInviteLink {
     ///
     ///    Note: Each administrator in a chat generates their own invite links. Bots can't use invite links generated by other administrators. If you want your bot to work with invite links, it will need to generate its own link using exportChatInviteLink – after this the link will become available to the bot via the getChat method. If your bot needs to generate a new invite link replacing its previous one, use exportChatInviteLink again.
     ///
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
 }
 impl TelegramMethod for exportChatInviteLink {
     const method_name: &'static str = "exportChatInviteLink";
+    const IDEMPOTENT: bool = false;
+    type Response = String;
 }
 ///
 ///Use this method to set a new profile photo for the chat. Photos can't be changed for private chats. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct setChatPhoto {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///New chat photo, uploaded using multipart/form-data
     pub photo: InputFile,
 }
 impl TelegramMethod for setChatPhoto {
     const method_name: &'static str = "setChatPhoto";
+    type Response = True;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default().field("photo", &self.photo)
+    }
 }
 ///
 ///Use this method to delete a chat photo. Photos can't be changed for private chats. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct deleteChatPhoto {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
 }
 impl TelegramMethod for deleteChatPhoto {
     const method_name: &'static str = "deleteChatPhoto";
+    type Response = True;
 }
 ///
 ///Use this method to change the title of a chat. Titles can't be changed for private chats. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct setChatTitle {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///New chat title, 1-255 characters
     pub title: String,
 }
 impl TelegramMethod for setChatTitle {
     const method_name: &'static str = "setChatTitle";
+    type Response = True;
 }
 ///
 ///Use this method to change the description of a group, a supergroup or a channel. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct setChatDescription {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///New chat description, 0-255 characters
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -925,14 +1126,17 @@ pub struct setChatDescription {
 }
 impl TelegramMethod for setChatDescription {
     const method_name: &'static str = "setChatDescription";
+    type Response = True;
 }
 ///
 ///Use this method to pin a message in a group, a supergroup, or a channel. The bot must be an administrator in the chat for this to work and must have the ‘can_pin_messages’ admin right in the supergroup or ‘can_edit_messages’ admin right in the channel. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct pinChatMessage {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Identifier of a message to pin
     pub message_id: Integer,
     ///Pass True, if it is not necessary to send a notification to all chat members about the new pinned message. Notifications are always disabled in channels.
@@ -942,98 +1146,123 @@ pub struct pinChatMessage {
 }
 impl TelegramMethod for pinChatMessage {
     const method_name: &'static str = "pinChatMessage";
+    type Response = True;
 }
 ///
 ///Use this method to unpin a message in a group, a supergroup, or a channel. The bot must be an administrator in the chat for this to work and must have the ‘can_pin_messages’ admin right in the supergroup or ‘can_edit_messages’ admin right in the channel. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct unpinChatMessage {
     ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
 }
 impl TelegramMethod for unpinChatMessage {
     const method_name: &'static str = "unpinChatMessage";
+    type Response = True;
 }
 ///
 ///Use this method for your bot to leave a group, supergroup or channel. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct leaveChat {
     ///Unique identifier for the target chat or username of the target supergroup or channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
 }
 impl TelegramMethod for leaveChat {
     const method_name: &'static str = "leaveChat";
+    type Response = True;
 }
 ///
 ///Use this method to get up to date information about the chat (current name of the user for one-on-one conversations, current username of a user, group or channel, etc.). Returns a Chat object on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct getChat {
     ///Unique identifier for the target chat or username of the target supergroup or channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
 }
 impl TelegramMethod for getChat {
     const method_name: &'static str = "getChat";
+    type Response = Chat;
 }
 ///
 ///Use this method to get a list of administrators in a chat. On success, returns an Array of ChatMember objects that contains information about all chat administrators except other bots. If the chat is a group or a supergroup and no administrators were appointed, only the creator will be returned.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct getChatAdministrators {
     ///Unique identifier for the target chat or username of the target supergroup or channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
 }
 impl TelegramMethod for getChatAdministrators {
     const method_name: &'static str = "getChatAdministrators";
+    type Response = Vec<ChatMember>;
 }
 ///
 ///Use this method to get the number of members in a chat. Returns Int on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct getChatMembersCount {
     ///Unique identifier for the target chat or username of the target supergroup or channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
 }
 impl TelegramMethod for getChatMembersCount {
     const method_name: &'static str = "getChatMembersCount";
+    type Response = Integer;
 }
 ///
 ///Use this method to get information about a member of a chat. Returns a ChatMember object on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct getChatMember {
     ///Unique identifier for the target chat or username of the target supergroup or channel (in the format @channelusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Unique identifier of the target user
     pub user_id: Integer,
 }
 impl TelegramMethod for getChatMember {
     const method_name: &'static str = "getChatMember";
+    type Response = ChatMember;
 }
 ///
 ///Use this method to set a new group sticker set for a supergroup. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Use the field can_set_sticker_set optionally returned in getChat requests to check if the bot can use this method. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct setChatStickerSet {
     ///Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
     ///Name of the sticker set to be set as the group sticker set
     pub sticker_set_name: String,
 }
 impl TelegramMethod for setChatStickerSet {
     const method_name: &'static str = "setChatStickerSet";
+    type Response = True;
 }
 ///
 ///Use this method to delete a group sticker set from a supergroup. The bot must be an administrator in the chat for this to work and must have the appropriate admin rights. Use the field can_set_sticker_set optionally returned in getChat requests to check if the bot can use this method. Returns True on success.
 #[derive(Serialize, Builder, Clone)]
 #[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
 pub struct deleteChatStickerSet {
     ///Unique identifier for the target chat or username of the target supergroup (in the format @supergroupusername)
-    pub chat_id: Or<Integer, String>,
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
 }
 impl TelegramMethod for deleteChatStickerSet {
     const method_name: &'static str = "deleteChatStickerSet";
+    type Response = True;
 }
 ///
 ///Use this method to send answers to callback queries sent from inline keyboards. The answer will be displayed to the user as a notification at the top of the chat screen or as an alert. On success, True is returned.
@@ -1066,4 +1295,636 @@ pub struct answerCallbackQuery {
 }
 impl TelegramMethod for answerCallbackQuery {
     const method_name: &'static str = "answerCallbackQuery";
+    type Response = True;
+}
+///
+///Use this method to edit text and game messages. On success, if edited message is sent by the bot, the edited Message is returned, otherwise True is returned.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct editMessageText {
+    ///Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<ChatId>,
+    ///Required if inline_message_id is not specified. Identifier of the message to edit
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<Integer>,
+    ///Required if chat_id and message_id are not specified. Identifier of the inline message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    ///New text of the message
+    pub text: String,
+    ///Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in your bot's message.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    ///Disables link previews for links in this message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_web_page_preview: Option<Boolean>,
+    ///A JSON-serialized object for an inline keyboard.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+impl TelegramMethod for editMessageText {
+    const method_name: &'static str = "editMessageText";
+    type Response = Or<Message, True>;
+}
+///
+///Use this method to edit captions of messages. On success, if edited message is sent by the bot, the edited Message is returned, otherwise True is returned.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct editMessageCaption {
+    ///Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<ChatId>,
+    ///Required if inline_message_id is not specified. Identifier of the message to edit
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<Integer>,
+    ///Required if chat_id and message_id are not specified. Identifier of the inline message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    ///New caption of the message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    ///Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_mode: Option<ParseMode>,
+    ///A JSON-serialized object for an inline keyboard.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+impl TelegramMethod for editMessageCaption {
+    const method_name: &'static str = "editMessageCaption";
+    type Response = Or<Message, True>;
+}
+///
+///Use this method to edit animation, audio, document, photo, or video messages. If a message is a part of a message album, then it can be edited only to a photo or a video. Otherwise, message type can be changed arbitrarily. When inline message is edited, new file can't be uploaded. Use previously uploaded file via its file_id or specify a URL. On success, if the edited message was sent by the bot, the edited Message is returned, otherwise True is returned.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct editMessageMedia {
+    ///Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<ChatId>,
+    ///Required if inline_message_id is not specified. Identifier of the message to edit
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<Integer>,
+    ///Required if chat_id and message_id are not specified. Identifier of the inline message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    ///A JSON-serialized object for a new media content of the message
+    pub media: InputMedia,
+    ///A JSON-serialized object for a new inline keyboard.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+impl TelegramMethod for editMessageMedia {
+    const method_name: &'static str = "editMessageMedia";
+    type Response = Or<Message, True>;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default().attached(&self.media)
+    }
+}
+///
+///Use this method to edit only the reply markup of messages. On success, if edited message is sent by the bot, the edited Message is returned, otherwise True is returned.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct editMessageReplyMarkup {
+    ///Required if inline_message_id is not specified. Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[builder(default, setter(into, strip_option))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<ChatId>,
+    ///Required if inline_message_id is not specified. Identifier of the message to edit
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<Integer>,
+    ///Required if chat_id and message_id are not specified. Identifier of the inline message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+    ///A JSON-serialized object for an inline keyboard.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+impl TelegramMethod for editMessageReplyMarkup {
+    const method_name: &'static str = "editMessageReplyMarkup";
+    type Response = Or<Message, True>;
+}
+///
+///Use this method to stop a poll which was sent by the bot. On success, the stopped Poll with the final results is returned.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct stopPoll {
+    ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
+    ///Identifier of the original message with the poll
+    pub message_id: Integer,
+    ///A JSON-serialized object for a new message inline keyboard.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+impl TelegramMethod for stopPoll {
+    const method_name: &'static str = "stopPoll";
+    type Response = Poll;
+}
+///
+///Use this method to delete a message, including service messages, with the following limitations:
+///- A message can only be deleted if it was sent less than 48 hours ago.
+///- Bots can delete outgoing messages in private chats, groups, and supergroups.
+///- Bots can delete incoming messages in private chats.
+///- Bots granted can_post_messages permissions can delete outgoing messages in channels.
+///- If the bot is an administrator of a group, it can delete any message there.
+///- If the bot has can_delete_messages permission in a supergroup or a channel, it can delete any message there.
+///
+///Returns True on success.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct deleteMessage {
+    ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
+    ///Identifier of the message to delete
+    pub message_id: Integer,
+}
+impl TelegramMethod for deleteMessage {
+    const method_name: &'static str = "deleteMessage";
+    type Response = True;
+}
+///
+///Use this method to send answers to an inline query. On success, True is returned.
+///No more than 50 results per query are allowed.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct answerInlineQuery {
+    ///Unique identifier for the answered query
+    pub inline_query_id: String,
+    ///A JSON-serialized array of results for the inline query
+    pub results: Vec<InlineQueryResult>,
+    ///The maximum amount of time in seconds that the result of the inline query may be cached on the server. Defaults to 300.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_time: Option<Integer>,
+    ///Pass True, if results may be cached on the server side only for the user that sent the query. By default, results may be returned to any user who sends the same query
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_personal: Option<Boolean>,
+    ///Pass the offset that a client should send in the next query with the same text to receive more results. Pass an empty string if there are no more results or if you don‘t support pagination. Offset length can’t exceed 64 bytes.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<String>,
+    ///If passed, clients will display a button with specified text that switches the user to a private chat with the bot and sends the bot a start message with the parameter switch_pm_parameter
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_pm_text: Option<String>,
+    ///Deep-linking parameter for the /start message sent to the bot when user presses the switch button. 1-64 characters, only A-Z, a-z, 0-9, _ and - are allowed.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub switch_pm_parameter: Option<String>,
+}
+impl TelegramMethod for answerInlineQuery {
+    const method_name: &'static str = "answerInlineQuery";
+    type Response = True;
+}
+///
+///Use this method to send invoices. On success, the sent Message is returned.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct sendInvoice {
+    ///Unique identifier for the target private chat
+    pub chat_id: Integer,
+    ///Product name, 1-32 characters
+    pub title: String,
+    ///Product description, 1-255 characters
+    pub description: String,
+    ///Bot-defined invoice payload, 1-128 bytes. This will not be displayed to the user, use for your internal processes.
+    pub payload: String,
+    ///Payments provider token, obtained via Botfather
+    pub provider_token: String,
+    ///Unique deep-linking parameter that can be used to generate this invoice when used as a start parameter
+    pub start_parameter: String,
+    ///Three-letter ISO 4217 currency code
+    pub currency: String,
+    ///Price breakdown, a list of components (e.g. product price, tax, discount, delivery cost, delivery tax, bonus, etc.)
+    pub prices: Vec<LabeledPrice>,
+    ///JSON-encoded data about the invoice, which will be shared with the payment provider. A detailed description of required fields should be provided by the payment provider.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_data: Option<String>,
+    ///URL of the product photo for the invoice. Can be a photo of the goods or a marketing image for a service. People like it better when they see what they are paying for.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+    ///Photo size
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_size: Option<Integer>,
+    ///Photo width
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_width: Option<Integer>,
+    ///Photo height
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub photo_height: Option<Integer>,
+    ///Pass True, if you require the user's full name to complete the order
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_name: Option<Boolean>,
+    ///Pass True, if you require the user's phone number to complete the order
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_phone_number: Option<Boolean>,
+    ///Pass True, if you require the user's email address to complete the order
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_email: Option<Boolean>,
+    ///Pass True, if you require the user's shipping address to complete the order
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub need_shipping_address: Option<Boolean>,
+    ///Pass True, if user's phone number should be sent to provider
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_phone_number_to_provider: Option<Boolean>,
+    ///Pass True, if user's email address should be sent to provider
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email_to_provider: Option<Boolean>,
+    ///Pass True, if the final price depends on the shipping method
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_flexible: Option<Boolean>,
+    ///Sends the message silently. Users will receive a notification with no sound.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<Boolean>,
+    ///If the message is a reply, ID of the original message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<Integer>,
+    ///A JSON-serialized object for an inline keyboard. If empty, one 'Pay total price' button will be shown. If not empty, the first button must be a Pay button.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+impl TelegramMethod for sendInvoice {
+    const method_name: &'static str = "sendInvoice";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
+}
+///
+///If you sent an invoice requesting a shipping address and the parameter is_flexible was specified, the Bot API will send an Update with a shipping_query field to the bot. Use this method to reply to shipping queries. On success, True is returned.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct answerShippingQuery {
+    ///Unique identifier for the query to be answered
+    pub shipping_query_id: String,
+    ///Specify True if delivery to the specified address is possible and False if there are any problems (for example, if delivery to the specified address is not possible)
+    pub ok: Boolean,
+    ///Required if ok is True. A JSON-serialized array of available shipping options.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_options: Option<Vec<ShippingOption>>,
+    ///Required if ok is False. Error message in human readable form that explains why it is impossible to complete the order (e.g. "Sorry, delivery to your desired address is unavailable'). Telegram will display this message to the user.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+impl TelegramMethod for answerShippingQuery {
+    const method_name: &'static str = "answerShippingQuery";
+    type Response = True;
+}
+///
+///Once the user has confirmed their payment and shipping details, the Bot API sends the final confirmation in the form of an Update with the field pre_checkout_query. Use this method to respond to such pre-checkout queries. On success, True is returned. Note: The Bot API must receive an answer within 10 seconds after the pre-checkout query was sent.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct answerPreCheckoutQuery {
+    ///Unique identifier for the query to be answered
+    pub pre_checkout_query_id: String,
+    ///Specify True if everything is alright (goods are available, etc.) and the bot is ready to proceed with the order. Use False if there are any problems.
+    pub ok: Boolean,
+    ///Required if ok is False. Error message in human readable form that explains the reason for failure to proceed with the checkout (e.g. "Sorry, somebody just bought the last of our amazing black T-shirts while you were busy filling out your payment details. Please choose a different color or garment!"). Telegram will display this message to the user.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+impl TelegramMethod for answerPreCheckoutQuery {
+    const method_name: &'static str = "answerPreCheckoutQuery";
+    type Response = True;
+}
+///
+///Use this method to send static .WEBP or animated .TGS stickers. On success, the sent Message is returned.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct sendSticker {
+    ///Unique identifier for the target chat or username of the target channel (in the format @channelusername)
+    #[builder(setter(into))]
+    pub chat_id: ChatId,
+    ///Sticker to send. Pass a file_id as String to send a file that exists on the Telegram servers (recommended), pass an HTTP URL as a String for Telegram to get a .webp file from the Internet, or upload a new one using multipart/form-data.
+    pub sticker: Or<InputFile, String>,
+    ///Sends the message silently. Users will receive a notification with no sound.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<Boolean>,
+    ///If the message is a reply, ID of the original message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<Integer>,
+    ///Additional interface options. A JSON-serialized object for an inline keyboard, custom reply keyboard, instructions to remove reply keyboard or to force a reply from the user.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+impl TelegramMethod for sendSticker {
+    const method_name: &'static str = "sendSticker";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default().field("sticker", &self.sticker)
+    }
+}
+///
+///Use this method to get a sticker set. On success, a StickerSet object is returned.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct getStickerSet {
+    ///Name of the sticker set
+    pub name: String,
+}
+impl TelegramMethod for getStickerSet {
+    const method_name: &'static str = "getStickerSet";
+    type Response = StickerSet;
+}
+///
+///Use this method to upload a .png file with a sticker for later use in createNewStickerSet and addStickerToSet methods (can be used multiple times). Returns the uploaded File on success.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct uploadStickerFile {
+    ///User identifier of sticker file owner
+    pub user_id: Integer,
+    ///Png image with the sticker, must be up to 512 kilobytes in size, dimensions must not exceed 512px, and either width or height must be exactly 512px.
+    pub png_sticker: InputFile,
+}
+impl TelegramMethod for uploadStickerFile {
+    const method_name: &'static str = "uploadStickerFile";
+    const IDEMPOTENT: bool = false;
+    type Response = File;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default().field("png_sticker", &self.png_sticker)
+    }
+}
+///
+///Use this method to create new sticker set owned by a user. The bot will be able to edit the created sticker set. Returns True on success.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct createNewStickerSet {
+    ///User identifier of created sticker set owner
+    pub user_id: Integer,
+    ///Short name of sticker set, to be used in t.me/addstickers/ URLs (e.g., animals). Can contain only english letters, digits and underscores. Must begin with a letter, can't contain consecutive underscores and must end in “_by_<bot username>”. <bot_username> is case insensitive. 1-64 characters.
+    pub name: String,
+    ///Sticker set title, 1-64 characters
+    pub title: String,
+    ///Png image with the sticker, must be up to 512 kilobytes in size, dimensions must not exceed 512px, and either width or height must be exactly 512px. Pass a file_id as a String to send a file that already exists on the Telegram servers, pass an HTTP URL as a String for Telegram to get a file from the Internet, or upload a new one using multipart/form-data.
+    pub png_sticker: Or<InputFile, String>,
+    ///One or more emoji corresponding to the sticker
+    pub emojis: String,
+    ///Pass True, if a set of mask stickers should be created
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contains_masks: Option<Boolean>,
+    ///A JSON-serialized object for position where the mask should be placed on faces
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+}
+impl TelegramMethod for createNewStickerSet {
+    const method_name: &'static str = "createNewStickerSet";
+    const IDEMPOTENT: bool = false;
+    type Response = True;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default().field("png_sticker", &self.png_sticker)
+    }
+}
+///
+///Use this method to add a new sticker to a set created by the bot. Returns True on success.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct addStickerToSet {
+    ///User identifier of sticker set owner
+    pub user_id: Integer,
+    ///Sticker set name
+    pub name: String,
+    ///Png image with the sticker, must be up to 512 kilobytes in size, dimensions must not exceed 512px, and either width or height must be exactly 512px. Pass a file_id as a String to send a file that already exists on the Telegram servers, pass an HTTP URL as a String for Telegram to get a file from the Internet, or upload a new one using multipart/form-data.
+    pub png_sticker: Or<InputFile, String>,
+    ///One or more emoji corresponding to the sticker
+    pub emojis: String,
+    ///A JSON-serialized object for position where the mask should be placed on faces
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mask_position: Option<MaskPosition>,
+}
+impl TelegramMethod for addStickerToSet {
+    const method_name: &'static str = "addStickerToSet";
+    const IDEMPOTENT: bool = false;
+    type Response = True;
+    fn input_files(&self) -> InputFiles<'_> {
+        InputFiles::default().field("png_sticker", &self.png_sticker)
+    }
+}
+///
+///Use this method to move a sticker in a set created by the bot to a specific position. Returns True on success.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct setStickerPositionInSet {
+    ///File identifier of the sticker
+    pub sticker: String,
+    ///New sticker position in the set, zero-based
+    pub position: Integer,
+}
+impl TelegramMethod for setStickerPositionInSet {
+    const method_name: &'static str = "setStickerPositionInSet";
+    type Response = True;
+}
+///
+///Use this method to delete a sticker from a set created by the bot. Returns True on success.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct deleteStickerFromSet {
+    ///File identifier of the sticker
+    pub sticker: String,
+}
+impl TelegramMethod for deleteStickerFromSet {
+    const method_name: &'static str = "deleteStickerFromSet";
+    type Response = True;
+}
+///
+///Use this method to send a game. On success, the sent Message is returned.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct sendGame {
+    ///Unique identifier for the target chat
+    pub chat_id: Integer,
+    ///Short name of the game, serves as the unique identifier for the game. Set up your games via Botfather.
+    pub game_short_name: String,
+    ///Sends the message silently. Users will receive a notification with no sound.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_notification: Option<Boolean>,
+    ///If the message is a reply, ID of the original message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to_message_id: Option<Integer>,
+    ///A JSON-serialized object for an inline keyboard. If empty, one ‘Play game_title’ button will be shown. If not empty, the first button must launch the game.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+impl TelegramMethod for sendGame {
+    const method_name: &'static str = "sendGame";
+    const IDEMPOTENT: bool = false;
+    const RATE_LIMITED: bool = true;
+    type Response = Message;
+}
+///
+///Use this method to set the score of the specified user in a game. On success, if the message was sent by the bot, returns the edited Message, otherwise returns True. Returns an error, if the new score is not greater than the user's current score in the chat and force is False.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct setGameScore {
+    ///User identifier
+    pub user_id: Integer,
+    ///New score, must be non-negative
+    pub score: Integer,
+    ///Pass True, if the high score is allowed to decrease. This can be useful when fixing mistakes or banning cheaters
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<Boolean>,
+    ///Pass True, if the game message should not be automatically edited to include the current scoreboard
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_edit_message: Option<Boolean>,
+    ///Required if inline_message_id is not specified. Unique identifier for the target chat
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<Integer>,
+    ///Required if inline_message_id is not specified. Identifier of the sent message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<Integer>,
+    ///Required if chat_id and message_id are not specified. Identifier of the inline message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+}
+impl TelegramMethod for setGameScore {
+    const method_name: &'static str = "setGameScore";
+    type Response = Or<Message, True>;
+}
+///
+///Use this method to get data for high score tables. Will return the score of the specified user and several of their neighbors in a game. On success, returns an Array of GameHighScore objects.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct getGameHighScores {
+    ///Target user id
+    pub user_id: Integer,
+    ///Required if inline_message_id is not specified. Unique identifier for the target chat
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<Integer>,
+    ///Required if inline_message_id is not specified. Identifier of the sent message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<Integer>,
+    ///Required if chat_id and message_id are not specified. Identifier of the inline message
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_message_id: Option<String>,
+}
+impl TelegramMethod for getGameHighScores {
+    const method_name: &'static str = "getGameHighScores";
+    type Response = Vec<GameHighScore>;
+}
+///
+///Informs a user that some of the Telegram Passport elements they provided contains errors. The user will not be able to re-submit their Passport to you until the errors are fixed (the contents of the field for which you returned the error must change). Returns True on success.
+#[derive(Serialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct setPassportDataErrors {
+    ///User identifier
+    pub user_id: Integer,
+    ///A JSON-serialized array describing the errors
+    pub errors: Vec<PassportElementError>,
+}
+impl TelegramMethod for setPassportDataErrors {
+    const method_name: &'static str = "setPassportDataErrors";
+    type Response = True;
+}
+
+validate_chat_ids! {
+    sendMessageBuilder { chat_id }
+    forwardMessageBuilder { chat_id, from_chat_id }
+    sendPhotoBuilder { chat_id }
+    sendAudioBuilder { chat_id }
+    sendDocumentBuilder { chat_id }
+    sendVideoBuilder { chat_id }
+    sendAnimationBuilder { chat_id }
+    sendVoiceBuilder { chat_id }
+    sendVideoNoteBuilder { chat_id }
+    sendMediaGroupBuilder { chat_id }
+    sendLocationBuilder { chat_id }
+    editMessageLiveLocationBuilder { chat_id }
+    stopMessageLiveLocationBuilder { chat_id }
+    sendVenueBuilder { chat_id }
+    sendContactBuilder { chat_id }
+    sendPollBuilder { chat_id }
+    sendChatActionBuilder { chat_id }
+    kickChatMemberBuilder { chat_id }
+    unbanChatMemberBuilder { chat_id }
+    restrictChatMemberBuilder { chat_id }
+    promoteChatMemberBuilder { chat_id }
+    setChatPermissionsBuilder { chat_id }
+    exportChatInviteLinkBuilder { chat_id }
+    setChatPhotoBuilder { chat_id }
+    deleteChatPhotoBuilder { chat_id }
+    setChatTitleBuilder { chat_id }
+    setChatDescriptionBuilder { chat_id }
+    pinChatMessageBuilder { chat_id }
+    unpinChatMessageBuilder { chat_id }
+    leaveChatBuilder { chat_id }
+    getChatBuilder { chat_id }
+    getChatAdministratorsBuilder { chat_id }
+    getChatMembersCountBuilder { chat_id }
+    getChatMemberBuilder { chat_id }
+    setChatStickerSetBuilder { chat_id }
+    deleteChatStickerSetBuilder { chat_id }
+    editMessageTextBuilder { chat_id }
+    editMessageCaptionBuilder { chat_id }
+    editMessageMediaBuilder { chat_id }
+    editMessageReplyMarkupBuilder { chat_id }
+    stopPollBuilder { chat_id }
+    deleteMessageBuilder { chat_id }
+    sendStickerBuilder { chat_id }
 }
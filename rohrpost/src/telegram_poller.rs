@@ -0,0 +1,178 @@
+use crate::error::Error;
+use crate::telegram_methods;
+use crate::telegram_sender::TelegramSender;
+use crate::telegram_types::Update;
+use async_std::task;
+use futures::stream::{self, LocalBoxStream, StreamExt};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+///Config for the poller
+pub struct Config {
+    timeout: i64,
+    limit: Option<i64>,
+    allowed_updates: Option<Vec<String>>,
+    error_delay: Duration,
+}
+
+impl Config {
+    ///timeout is the long poll timeout in seconds that is passed to getUpdates
+    pub fn new(timeout: i64) -> Self {
+        Config {
+            timeout,
+            limit: None,
+            allowed_updates: None,
+            error_delay: Duration::from_secs(1),
+        }
+    }
+    ///Maximum number of updates fetched per getUpdates call (1-100)
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+    ///Only receive updates of the given kinds, for example "message" or "callback_query"
+    pub fn allowed_updates(mut self, allowed_updates: Vec<String>) -> Self {
+        self.allowed_updates = Some(allowed_updates);
+        self
+    }
+    ///Time to wait before polling again after a failed getUpdates call
+    pub fn error_delay(mut self, error_delay: Duration) -> Self {
+        self.error_delay = error_delay;
+        self
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::new(30)
+    }
+}
+
+///Poller
+/// calls getUpdates in a loop and keeps track of the offset, so every update is only returned once.
+/// Use this instead of the TelegramReceiver if the bot can not expose a port for a webhook.
+pub struct TelegramPoller<'a> {
+    sender: &'a TelegramSender,
+    config: Config,
+    offset: Option<i64>,
+    pending: VecDeque<Update>,
+    failed: bool,
+}
+
+impl<'a> TelegramPoller<'a> {
+    pub fn new(sender: &'a TelegramSender, config: Config) -> Self {
+        TelegramPoller {
+            sender,
+            config,
+            offset: None,
+            pending: VecDeque::new(),
+            failed: false,
+        }
+    }
+    ///The offset that will be passed to the next getUpdates call
+    pub fn offset(&self) -> Option<i64> {
+        self.offset
+    }
+    ///Performs a single getUpdates call and advances the offset past all returned updates.
+    pub async fn poll(&mut self) -> Result<Vec<Update>, Error> {
+        let mut builder = telegram_methods::getUpdatesBuilder::default();
+        builder.timeout(self.config.timeout);
+        if let Some(offset) = self.offset {
+            builder.offset(offset);
+        }
+        if let Some(limit) = self.config.limit {
+            builder.limit(limit);
+        }
+        if let Some(allowed_updates) = &self.config.allowed_updates {
+            builder.allowed_updates(allowed_updates.clone());
+        }
+        let method = builder.build().unwrap();
+        let updates = self.sender.call(&method).await?;
+        if let Some(last) = updates.iter().map(|update| update.update_id).max() {
+            self.offset = Some(last + 1);
+        }
+        Ok(updates)
+    }
+    ///Returns the next update, polling as often as necessary until one arrives.
+    pub async fn next_update(&mut self) -> Result<Update, Error> {
+        loop {
+            if let Some(update) = self.pending.pop_front() {
+                return Ok(update);
+            }
+            if self.failed {
+                task::sleep(self.config.error_delay).await;
+            }
+            match self.poll().await {
+                Ok(updates) => {
+                    self.failed = false;
+                    self.pending.extend(updates);
+                }
+                Err(err) => {
+                    self.failed = true;
+                    return Err(err);
+                }
+            }
+        }
+    }
+    ///Turns the poller into an endless stream of updates.
+    /// Errors are passed on to the consumer, the stream continues polling afterwards.
+    pub fn into_stream(self) -> LocalBoxStream<'a, Result<Update, Error>> {
+        stream::unfold(self, |mut poller| async move {
+            let update = poller.next_update().await;
+            Some((update, poller))
+        })
+        .boxed_local()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::retry::RetryPolicy;
+    use crate::telegram_sender::mock_server::{self, respond};
+    use futures::executor::block_on;
+    use serde_json::Value;
+    use std::time::Instant;
+
+    #[test]
+    fn offset_advances_and_errors_back_off() {
+        block_on(async {
+            let (listener, config) = mock_server::bind();
+            let sender = TelegramSender::new(config.retry_policy(RetryPolicy::none()));
+            let error_delay = Duration::from_millis(200);
+            let mut poller = TelegramPoller::new(&sender, Config::new(0).error_delay(error_delay));
+            let server = async {
+                let mut http_stream = mock_server::accept(&listener).await;
+                let answers = [
+                    r#"{"ok":true,"result":[{"update_id":7},{"update_id":9}]}"#,
+                    r#"{"ok":false,"error_code":409,"description":"Conflict"}"#,
+                    r#"{"ok":true,"result":[{"update_id":10}]}"#,
+                ];
+                let mut offsets = Vec::new();
+                let mut answered = Instant::now();
+                for answer in answers.iter() {
+                    let request = http_stream.read().await.unwrap();
+                    let body: Value = serde_json::from_slice(request.get_body()).unwrap();
+                    offsets.push((body["offset"].as_i64(), answered.elapsed()));
+                    respond(&mut http_stream, answer).await;
+                    answered = Instant::now();
+                }
+                assert_eq!(offsets[0].0, None);
+                assert_eq!(offsets[1].0, Some(10));
+                assert!(offsets[1].1 < error_delay);
+                //the failed poll is repeated with the same offset after the error delay
+                assert_eq!(offsets[2].0, Some(10));
+                assert!(offsets[2].1 >= error_delay);
+            };
+            let client = async {
+                assert_eq!(poller.next_update().await.unwrap().update_id, 7);
+                assert_eq!(poller.next_update().await.unwrap().update_id, 9);
+                assert_eq!(poller.offset(), Some(10));
+                assert!(poller.next_update().await.is_err());
+                assert_eq!(poller.next_update().await.unwrap().update_id, 10);
+                assert_eq!(poller.offset(), Some(11));
+            };
+            futures::join!(server, client);
+        });
+    }
+}
@@ -8,6 +8,8 @@ use futures::future::FutureExt;
 use futures::stream::StreamExt;
 use futures::task::{LocalSpawn, LocalSpawnExt};
 use futures::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use http::{Method, StatusCode, Uri};
+use ring::constant_time;
 use rustls::internal::pemfile::{certs, pkcs8_private_keys};
 use rustls::{Certificate, NoClientAuth, PrivateKey, ServerConfig};
 use std::fs::File;
@@ -54,6 +56,7 @@ pub struct Config {
     certificate: PathBuf,
     certificate_key: PathBuf,
     webhook_path: String,
+    secret_token: Option<String>,
 }
 
 impl Config {
@@ -68,8 +71,25 @@ impl Config {
             certificate,
             certificate_key,
             webhook_path,
+            secret_token: None,
         }
     }
+    ///Secret that telegram sends in the X-Telegram-Bot-Api-Secret-Token header of every update.
+    /// Requests without it are rejected with 403. 1-256 characters, only A-Z, a-z, 0-9, _ and - are allowed.
+    pub fn secret_token(mut self, secret_token: String) -> io::Result<Self> {
+        let valid = (1..=256).contains(&secret_token.len())
+            && secret_token
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-');
+        if !valid {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "secret token must be 1-256 characters of A-Z, a-z, 0-9, _ and -",
+            ));
+        }
+        self.secret_token = Some(secret_token);
+        Ok(self)
+    }
 }
 
 impl Config {
@@ -88,6 +108,68 @@ impl Config {
 
 pub type SendItem = (HttpMsg, sync::Sender<HttpMsg>);
 
+const SECRET_TOKEN_HEADER: &str = "x-telegram-bot-api-secret-token";
+
+///WebhookFilter
+/// decides which requests are updates from telegram, everything else is answered without passing it on
+#[derive(Clone)]
+pub struct WebhookFilter {
+    path: String,
+    secret_token: Option<String>,
+}
+
+impl WebhookFilter {
+    ///webhook is the url given to setWebhook or only its path
+    pub fn new(webhook: &str, secret_token: Option<String>) -> Self {
+        let path = match webhook.parse::<Uri>() {
+            Ok(uri) => String::from(uri.path()),
+            Err(_) => String::from(webhook),
+        };
+        WebhookFilter { path, secret_token }
+    }
+    ///Returns the status to answer with if the request is not an update:
+    /// 404 for other paths, 405 for other methods than POST and 403 for a missing or wrong secret token
+    pub fn check(&self, http_msg: &HttpMsg) -> Result<(), StatusCode> {
+        let request = match http_msg {
+            HttpMsg::Request(request) => request,
+            HttpMsg::Response(_) => return Err(StatusCode::BAD_REQUEST),
+        };
+        if request.uri().path() != self.path {
+            return Err(StatusCode::NOT_FOUND);
+        }
+        if request.method() != Method::POST {
+            return Err(StatusCode::METHOD_NOT_ALLOWED);
+        }
+        if let Some(secret_token) = &self.secret_token {
+            let header = request
+                .headers()
+                .get(SECRET_TOKEN_HEADER)
+                .map(|value| value.as_bytes())
+                .unwrap_or(b"");
+            //constant time, so the token can not be guessed byte by byte
+            if constant_time::verify_slices_are_equal(header, secret_token.as_bytes()).is_err() {
+                return Err(StatusCode::FORBIDDEN);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn rejection(status: StatusCode) -> HttpMsg {
+    let mut response = HttpMsg::new_respone(status.as_u16());
+    if let HttpMsg::Response(response) = &mut response {
+        response
+            .headers_mut()
+            .insert("content-length", http::HeaderValue::from(0));
+        if status == StatusCode::METHOD_NOT_ALLOWED {
+            response
+                .headers_mut()
+                .insert("allow", http::HeaderValue::from_static("POST"));
+        }
+    }
+    response
+}
+
 ///Receiver
 /// accepts tcp connections on the speciefied port
 /// for each connection creates a new task and hands them of to the TcpStreamHandler
@@ -104,14 +186,17 @@ pub struct TelegramReceiver {
 struct TcpStreamHandler {
     tcp_stream: TcpStream,
     tls_acceptor: TlsAcceptor,
+    filter: WebhookFilter,
     stop: sync::Receiver<()>,
     output: sync::Sender<SendItem>,
 }
 
 ///HttpStreamHandler
-/// reads from the provided HttpStream in a loop and dispatches the read HttpMsg and the HttpStream into output
+/// reads from the provided HttpStream in a loop and dispatches the read HttpMsg and the HttpStream into output.
+/// Requests the WebhookFilter rejects are answered right away and not dispatched.
 pub struct HttpStreamHandler {
     http_stream: HttpStream<async_tls::server::TlsStream<TcpStream>>,
+    filter: WebhookFilter,
     stop: sync::Receiver<()>,
     output: sync::Sender<SendItem>,
 }
@@ -135,6 +220,9 @@ impl TelegramReceiver {
     pub fn get_webhook_uri(&self) -> String {
         self.config.webhook_path.clone()
     }
+    pub fn get_secret_token(&self) -> Option<String> {
+        self.config.secret_token.clone()
+    }
     pub async fn run<S>(self, executor: S)
     where
         S: LocalSpawn,
@@ -142,6 +230,8 @@ impl TelegramReceiver {
         let mut stop_fused = self.stop.clone().fuse();
         let tcp_listener = TcpListener::bind(&self.config.addr).await.unwrap();
         let mut incoming = tcp_listener.incoming().fuse();
+        let filter =
+            WebhookFilter::new(&self.config.webhook_path, self.config.secret_token.clone());
 
         loop {
             select! {
@@ -156,6 +246,7 @@ impl TelegramReceiver {
                             let tcp_stream_handler = TcpStreamHandler{
                                 tcp_stream: tcp_stream,
                                 tls_acceptor: self.tls_acceptor.clone(),
+                                filter: filter.clone(),
                                 stop: self.stop.clone(),
                                 output: self.output.clone(),
                             };
@@ -189,6 +280,7 @@ impl TcpStreamHandler {
                 let stream = result.ok().unwrap();
                 let http_stream_handler = HttpStreamHandler {
                     http_stream: HttpStream::new(stream),
+                    filter: self.filter,
                     stop: self.stop,
                     output: self.output,
                 };
@@ -201,11 +293,13 @@ impl TcpStreamHandler {
 impl HttpStreamHandler {
     pub fn new(
         http_stream: HttpStream<async_tls::server::TlsStream<TcpStream>>,
+        filter: WebhookFilter,
         stop: sync::Receiver<()>,
         output: sync::Sender<SendItem>,
     ) -> Self {
         HttpStreamHandler {
             http_stream,
+            filter,
             stop,
             output,
         }
@@ -225,6 +319,12 @@ impl HttpStreamHandler {
                         return;
                     }
                     let http_msg = http_msg.ok().unwrap();
+                    if let Err(status) = self.filter.check(&http_msg) {
+                        if self.http_stream.write(rejection(status)).await.is_err() {
+                            return;
+                        }
+                        continue;
+                    }
                     //println!("{:?}", http_msg);
                     println!(
                         "{}",
@@ -246,3 +346,62 @@ impl HttpStreamHandler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, uri: &str, secret_token: Option<&str>) -> HttpMsg {
+        let mut request = http::Request::builder().method(method).uri(uri);
+        if let Some(secret_token) = secret_token {
+            request = request.header(SECRET_TOKEN_HEADER, secret_token);
+        }
+        HttpMsg::Request(request.body(Vec::new()).unwrap())
+    }
+
+    #[test]
+    fn only_updates_pass_the_filter() {
+        let filter = WebhookFilter::new(
+            "https://example.org:8443/hook",
+            Some(String::from("s3cret")),
+        );
+        assert_eq!(
+            filter.check(&request("POST", "/hook", Some("s3cret"))),
+            Ok(())
+        );
+        assert_eq!(
+            filter.check(&request("POST", "/other", Some("s3cret"))),
+            Err(StatusCode::NOT_FOUND)
+        );
+        assert_eq!(
+            filter.check(&request("GET", "/hook", Some("s3cret"))),
+            Err(StatusCode::METHOD_NOT_ALLOWED)
+        );
+        assert_eq!(
+            filter.check(&request("POST", "/hook", Some("s3cre"))),
+            Err(StatusCode::FORBIDDEN)
+        );
+        assert_eq!(
+            filter.check(&request("POST", "/hook", None)),
+            Err(StatusCode::FORBIDDEN)
+        );
+    }
+
+    #[test]
+    fn secret_token_is_validated() {
+        let config = || {
+            Config::new(
+                "127.0.0.1:8443".parse().unwrap(),
+                PathBuf::from("cert.pem"),
+                PathBuf::from("key.pem"),
+                String::from("/hook"),
+            )
+        };
+        assert!(config().secret_token(String::from("Abc_09-z")).is_ok());
+        assert!(config().secret_token("a".repeat(256)).is_ok());
+        assert!(config().secret_token(String::new()).is_err());
+        assert!(config().secret_token("a".repeat(257)).is_err());
+        assert!(config().secret_token(String::from("s3cret!")).is_err());
+        assert!(config().secret_token(String::from("ünï")).is_err());
+    }
+}
@@ -1,108 +1,1062 @@
-use crate::http_stream;
+use crate::chat_id::ChatId;
+use crate::download::Downloadable;
+use crate::error::Error;
 use crate::http_stream::{HttpMsg, HttpStream};
+use crate::multipart::{self, Body};
+use crate::rate_limit::{self, RateLimiter, RateLimits};
+use crate::retry::RetryPolicy;
 use crate::telegram_methods;
 use crate::telegram_methods::TelegramMethod;
 use crate::telegram_receiver::TelegramReceiver;
+use crate::telegram_types::{Chat, File, ResponseParameters, User};
 use async_std::net::TcpStream;
+use async_std::task;
+use async_tls::client::TlsStream;
 use async_tls::TlsConnector;
-use futures::{AsyncRead, AsyncWrite};
+use futures::task::{Context, Poll};
+use futures::{AsyncRead, AsyncWrite, AsyncWriteExt};
 use http::request::Request;
+use http::Uri;
 use serde::ser::Serialize;
+use serde::Deserialize;
 use serde_json;
+use std::io;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
-const TELEGRAM_API_BASE: &'static str = "https://api.telegram.org/bot";
+const TELEGRAM_API_BASE: &str = "https://api.telegram.org";
+///Bots can download files of up to 20MB
+pub const MAX_DOWNLOAD_SIZE: u64 = 20 * 1024 * 1024;
+
+///Where the bot api is reached, parsed from a base url like "https://api.telegram.org"
+struct ApiBase {
+    tls: bool,
+    host: String,
+    port: u16,
+    //without trailing slash
+    path: String,
+}
+
+impl ApiBase {
+    fn parse(url: &str) -> io::Result<Self> {
+        let invalid = |reason: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid api base url {:?}: {}", url, reason),
+            )
+        };
+        let uri: Uri = url.parse().map_err(|_| invalid("could not parse"))?;
+        let tls = match uri.scheme_str() {
+            Some("https") => true,
+            Some("http") => false,
+            _ => return Err(invalid("scheme must be http or https")),
+        };
+        let host = uri.host().ok_or_else(|| invalid("host is missing"))?;
+        let port = uri.port_u16().unwrap_or(if tls { 443 } else { 80 });
+        if uri.query().is_some() {
+            return Err(invalid("query is not allowed"));
+        }
+        Ok(ApiBase {
+            tls,
+            host: String::from(host),
+            port,
+            path: String::from(uri.path().trim_end_matches('/')),
+        })
+    }
+    ///Value for the host header
+    fn authority(&self) -> String {
+        let default_port = if self.tls { 443 } else { 80 };
+        if self.port == default_port {
+            self.host.clone()
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+}
 
 pub struct Config {
     token: String,
+    api_base: ApiBase,
+    pool_size: usize,
+    idle_timeout: Duration,
+    max_download_size: u64,
+    rate_limits: Option<RateLimits>,
+    retry_policy: RetryPolicy,
 }
 
 impl Config {
     pub fn new(token: String) -> Self {
-        Config { token: token }
+        Config {
+            token,
+            api_base: ApiBase::parse(TELEGRAM_API_BASE).unwrap(),
+            pool_size: 4,
+            idle_timeout: Duration::from_secs(60),
+            max_download_size: MAX_DOWNLOAD_SIZE,
+            rate_limits: Some(RateLimits::new()),
+            retry_policy: RetryPolicy::new(),
+        }
+    }
+    ///Send all requests to another bot api server, for example a self-hosted one on "http://localhost:8081".
+    /// Defaults to "https://api.telegram.org".
+    pub fn api_base(mut self, api_base: &str) -> io::Result<Self> {
+        self.api_base = ApiBase::parse(api_base)?;
+        Ok(self)
+    }
+    ///Maximum number of idle connections that are kept open for reuse, 0 disables keep-alive
+    pub fn pool_size(mut self, pool_size: usize) -> Self {
+        self.pool_size = pool_size;
+        self
+    }
+    ///Idle connections older than this are closed instead of reused
+    pub fn idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+    ///Downloads of larger files fail with Error::FileTooLarge, defaults to MAX_DOWNLOAD_SIZE.
+    /// Self-hosted bot api servers allow larger files.
+    pub fn max_download_size(mut self, max_download_size: u64) -> Self {
+        self.max_download_size = max_download_size;
+        self
+    }
+    ///Limits for methods that send to a chat, defaults to the flood limits of telegram
+    pub fn rate_limits(mut self, rate_limits: RateLimits) -> Self {
+        self.rate_limits = Some(rate_limits);
+        self
+    }
+    ///Sends every method right away, flood errors are returned to the caller
+    pub fn no_rate_limits(mut self) -> Self {
+        self.rate_limits = None;
+        self
+    }
+    ///When call sends a method again after a transient failure, see RetryPolicy
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+}
+
+///A plain tcp stream or a tls stream, depending on the scheme of the api base
+enum Stream {
+    Plain(TcpStream),
+    Tls(Box<TlsStream<TcpStream>>),
+}
+
+impl AsyncRead for Stream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut() {
+            Stream::Plain(stream) => Pin::new(stream).poll_read(cx, buf),
+            Stream::Tls(stream) => Pin::new(stream.as_mut()).poll_read(cx, buf),
+        }
+    }
+}
+
+impl AsyncWrite for Stream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut() {
+            Stream::Plain(stream) => Pin::new(stream).poll_write(cx, buf),
+            Stream::Tls(stream) => Pin::new(stream.as_mut()).poll_write(cx, buf),
+        }
+    }
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            Stream::Plain(stream) => Pin::new(stream).poll_flush(cx),
+            Stream::Tls(stream) => Pin::new(stream.as_mut()).poll_flush(cx),
+        }
+    }
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            Stream::Plain(stream) => Pin::new(stream).poll_close(cx),
+            Stream::Tls(stream) => Pin::new(stream.as_mut()).poll_close(cx),
+        }
     }
 }
 
+type Connection = HttpStream<Stream>;
+
+///A connection waiting in the pool
+struct Idle {
+    connection: Connection,
+    since: Instant,
+}
+
+///The envelope telegram wraps around the result of every method call
+#[derive(Deserialize)]
+pub struct Response<T> {
+    ///True if the request was successful, result is only set in that case
+    pub ok: bool,
+    pub result: Option<T>,
+    ///Human-readable description of the result or the error
+    pub description: Option<String>,
+    ///Error code for unsuccessful requests, its contents are subject to change in the future
+    pub error_code: Option<i64>,
+    ///Can help to automatically handle the error
+    pub parameters: Option<ResponseParameters>,
+}
+
+impl<T> Response<T> {
+    ///Returns the result for successful responses and an Error::Api otherwise
+    pub fn into_result(self) -> Result<T, Error> {
+        match self {
+            Response {
+                ok: true,
+                result: Some(result),
+                ..
+            } => Ok(result),
+            Response {
+                description,
+                error_code,
+                parameters,
+                ..
+            } => {
+                let parameters = parameters.unwrap_or(ResponseParameters {
+                    migrate_to_chat_id: None,
+                    retry_after: None,
+                });
+                Err(Error::Api {
+                    error_code: error_code.unwrap_or(0),
+                    description: description.unwrap_or_default(),
+                    retry_after: parameters.retry_after,
+                    migrate_to_chat_id: parameters.migrate_to_chat_id,
+                })
+            }
+        }
+    }
+}
+
+///Sender
+/// calls telegram methods over a pool of keep-alive connections to the bot api
 pub struct TelegramSender {
     config: Config,
+    pool: Mutex<Vec<Idle>>,
+    me: Mutex<Option<User>>,
+    limiter: Option<RateLimiter>,
 }
 
 impl TelegramSender {
     pub fn new(config: Config) -> Self {
-        TelegramSender { config: config }
+        TelegramSender {
+            limiter: config.rate_limits.clone().map(RateLimiter::new),
+            config,
+            pool: Mutex::new(Vec::new()),
+            me: Mutex::new(None),
+        }
+    }
+    ///The user of the bot itself.
+    /// It is requested with getMe on the first call, later calls return the cached user.
+    pub async fn me(&self) -> Result<User, Error> {
+        if let Some(me) = self.me.lock().unwrap().as_ref() {
+            return Ok(me.clone());
+        }
+        let me = self.call(&telegram_methods::getMe {}).await?;
+        *self.me.lock().unwrap() = Some(me.clone());
+        Ok(me)
     }
     fn uri<M>(&self) -> String
     where
         M: TelegramMethod,
     {
         let mut temp = String::new();
-        temp += TELEGRAM_API_BASE;
+        temp += &self.config.api_base.path;
+        temp += "/bot";
         temp += &self.config.token;
         temp += "/";
         temp += M::method_name;
         temp
     }
+    ///The head of the request, the body is written separately with write_body
+    fn request<M>(&self, body: &Body) -> HttpMsg
+    where
+        M: TelegramMethod,
+    {
+        let req = Request::builder()
+            .method("POST")
+            .uri(&self.uri::<M>())
+            .header("host", self.config.api_base.authority())
+            .header("connection", "keep-alive")
+            .header("content-type", body.content_type());
+        let req = match body.content_length() {
+            Some(content_length) => req.header("content-length", content_length),
+            None => req.header("transfer-encoding", "chunked"),
+        };
+        HttpMsg::Request(req.body(Vec::new()).unwrap())
+    }
     pub async fn call_on_http_stream<M, S>(
         &self,
         method: &M,
         http_stream: &mut HttpStream<S>,
-    ) -> std::io::Result<()>
+    ) -> Result<(), Error>
     where
         M: TelegramMethod + Serialize,
         S: AsyncWrite + AsyncRead + std::marker::Unpin,
     {
-        let uri = self.uri::<M>();
-
-        //construct our message
-        let body = serde_json::to_vec(method).unwrap();
-        let req = Request::builder()
-            .method("POST")
-            .uri(&uri)
-            .header("host", "api.telegram.org")
-            .header("content-length", body.len())
-            .header("content-type", "application/json")
-            .body(body)
-            .unwrap();
-        let http_msg = HttpMsg::Request(req);
+        //construct our message, methods containing an InputFile are sent as multipart/form-data
+        let body = multipart::encode(method).await?;
+        let http_msg = self.request::<M>(&body);
 
         //send
         http_stream.write(http_msg).await?;
+        http_stream
+            .write_body(body.reader().await?, body.content_length())
+            .await?;
         Ok(())
     }
-    ///Calls the telegram method. Creates a new tls stream and returns the response.
-    pub async fn call<M>(&self, method: &M) -> std::io::Result<HttpMsg>
+    async fn connect(&self) -> io::Result<Connection> {
+        let api_base = &self.config.api_base;
+        let tcp_stream = TcpStream::connect((api_base.host.as_str(), api_base.port)).await?;
+        if !api_base.tls {
+            return Ok(HttpStream::new(Stream::Plain(tcp_stream)));
+        }
+        let connector = TlsConnector::default();
+        let handshake = connector.connect(&api_base.host, tcp_stream)?;
+        let tls_stream = handshake.await?;
+        Ok(HttpStream::new(Stream::Tls(Box::new(tls_stream))))
+    }
+    ///Takes the most recently used connection out of the pool.
+    /// Connections that idled for too long or were closed by the server in the meantime are dropped.
+    fn checkout(&self) -> Option<Connection> {
+        let mut pool = self.pool.lock().unwrap();
+        let idle_timeout = self.config.idle_timeout;
+        pool.retain(|idle| idle.since.elapsed() < idle_timeout);
+        while let Some(mut idle) = pool.pop() {
+            if !idle.connection.is_closed() {
+                return Some(idle.connection);
+            }
+        }
+        None
+    }
+    fn checkin(&self, connection: Connection) {
+        let mut pool = self.pool.lock().unwrap();
+        if pool.len() < self.config.pool_size {
+            pool.push(Idle {
+                connection,
+                since: Instant::now(),
+            });
+        }
+    }
+    ///Sends the request on a pooled connection and reads the head of the response, the body is left on the connection.
+    /// If writing to a reused connection fails because the server closed it, the request is written again on a new connection.
+    /// Once the request is written it is never sent again here, errors while reading the response are returned.
+    /// sent is set once the request may have reached the server, connection errors before that leave it false.
+    async fn send<F>(
+        &self,
+        request: F,
+        body: &Body,
+        sent: &mut bool,
+    ) -> Result<(Connection, HttpMsg), Error>
+    where
+        F: Fn() -> HttpMsg,
+    {
+        let pooled = self.checkout();
+        let reused = pooled.is_some();
+        let mut connection = match pooled {
+            Some(connection) => connection,
+            None => self.connect().await?,
+        };
+        //files are opened before anything is written, a file that can not be read does not break the connection
+        let reader = body.reader().await?;
+        match connection.write(request()).await {
+            Ok(()) => {}
+            //the server closed the idle connection, it did not get the request
+            Err(ref err) if reused && is_closed(err) => {
+                connection = self.connect().await?;
+                *sent = true;
+                connection.write(request()).await?;
+            }
+            Err(err) => {
+                *sent = true;
+                return Err(Error::IO(err));
+            }
+        }
+        *sent = true;
+        connection.write_body(reader, body.content_length()).await?;
+        let response = connection.read_head().await?;
+        Ok((connection, response))
+    }
+    ///Reads the body of the response and puts the connection back into the pool.
+    /// Responses without content-length, like chunked ones, are returned without body and their connection is closed,
+    /// as the end of the body is unknown.
+    async fn finish(
+        &self,
+        mut connection: Connection,
+        mut response: HttpMsg,
+    ) -> Result<HttpMsg, Error> {
+        let content_length = match response.content_length()? {
+            Some(content_length) => content_length,
+            None => return Ok(response),
+        };
+        let mut body = Vec::new();
+        connection.read_body(content_length, &mut body).await?;
+        if let HttpMsg::Response(response) = &mut response {
+            *response.body_mut() = body;
+        }
+        if response.keep_alive() {
+            self.checkin(connection);
+        }
+        Ok(response)
+    }
+    ///Calls the telegram method on a pooled connection and returns the raw response.
+    /// If a reused connection turns out to be closed before the method was written, it is written on a new connection.
+    pub async fn call_raw<M>(&self, method: &M) -> Result<HttpMsg, Error>
     where
         M: TelegramMethod + Serialize,
     {
-        let tcp_stream = TcpStream::connect("api.telegram.org:443").await?;
-        let connector = TlsConnector::default();
-        let handshake = connector.connect("api.telegram.org", tcp_stream)?;
-        let tls_stream = handshake.await?;
-        let mut http_stream = HttpStream::new(tls_stream);
-        //send
-        self.call_on_http_stream(method, &mut http_stream).await?;
-        //receive result
-        let http_msg = http_stream.read().await;
-        if http_msg.is_err() {
-            //io error or parsing error
-            match http_msg.err().unwrap() {
-                http_stream::Error::IO(err) => return Err(err),
-                http_stream::Error::Parse(()) => {
-                    println!("Could not parse");
-                    return Err(std::io::ErrorKind::InvalidData.into());
-                }
+        let body = multipart::encode(method).await?;
+        self.call_encoded::<M>(&body, &mut false).await
+    }
+    async fn call_encoded<M>(&self, body: &Body, sent: &mut bool) -> Result<HttpMsg, Error>
+    where
+        M: TelegramMethod,
+    {
+        let (connection, response) = self.send(|| self.request::<M>(body), body, sent).await?;
+        self.finish(connection, response).await
+    }
+    ///The chat the rate limiter tracks a message under.
+    /// A @username is resolved to the numeric id with getChat once, so that both share the same limits.
+    /// If getChat fails, the username is used.
+    async fn limited_chat_id(&self, limiter: &RateLimiter, chat_id: ChatId) -> ChatId {
+        let username = match &chat_id {
+            ChatId::Username(username) => username,
+            ChatId::Id(_) => return chat_id,
+        };
+        if let Some(id) = limiter.resolved(username) {
+            return ChatId::Id(id);
+        }
+        //call_encoded instead of call, which would wait for the limiter again
+        let method = telegram_methods::getChat {
+            chat_id: chat_id.clone(),
+        };
+        let chat = match multipart::encode(&method).await {
+            Ok(body) => self
+                .call_encoded::<telegram_methods::getChat>(&body, &mut false)
+                .await
+                .and_then(|http_msg| decode::<Chat>(&http_msg)),
+            Err(err) => Err(err),
+        };
+        match chat {
+            Ok(chat) => {
+                limiter.resolve(username, chat.id);
+                ChatId::Id(chat.id)
             }
+            Err(_) => chat_id,
         }
-        let http_msg = http_msg.ok().unwrap();
-        Ok(http_msg)
+    }
+    ///Calls the telegram method and returns the deserialized result.
+    /// Methods that send a message to a chat wait for the rate limiter first.
+    /// Transient failures are retried according to the RetryPolicy of the config,
+    /// after a flood error the rate limiter pauses all chats for retry_after seconds.
+    pub async fn call<M>(&self, method: &M) -> Result<M::Response, Error>
+    where
+        M: TelegramMethod + Serialize,
+    {
+        //encode only once, every attempt opens the files of the body again
+        let body = multipart::encode(method).await?;
+        let limited = match (&self.limiter, rate_limit::chat_id_of(method)) {
+            (Some(limiter), Some(chat_id)) if M::RATE_LIMITED => {
+                Some((limiter, self.limited_chat_id(limiter, chat_id).await))
+            }
+            _ => None,
+        };
+        let mut attempt = 0;
+        loop {
+            if let Some((limiter, chat_id)) = &limited {
+                limiter.acquire(chat_id).await;
+            }
+            let mut sent = false;
+            let result = match self.call_encoded::<M>(&body, &mut sent).await {
+                Ok(http_msg) => decode(&http_msg),
+                Err(err) => Err(err),
+            };
+            let err = match result {
+                Ok(response) => return Ok(response),
+                Err(err) => err,
+            };
+            attempt += 1;
+            let policy = &self.config.retry_policy;
+            let delay = match policy.retry(M::method_name, M::IDEMPOTENT, attempt, &err, sent) {
+                Some(delay) => delay,
+                None => return Err(err),
+            };
+            match (&err, &limited) {
+                (
+                    Error::Api {
+                        error_code: 429, ..
+                    },
+                    Some((limiter, _)),
+                ) => limiter.pause(delay),
+                _ => task::sleep(delay).await,
+            }
+        }
+    }
+    ///Streams the file with the file_path returned by getFile into writer and returns its size.
+    /// Files larger than the max_download_size of the config are refused before their content is read.
+    /// Responses that do not announce their size with content-length are refused as well.
+    pub async fn download_file_path<W>(&self, file_path: &str, writer: &mut W) -> Result<u64, Error>
+    where
+        W: AsyncWrite + std::marker::Unpin,
+    {
+        let uri = format!(
+            "{}/file/bot{}/{}",
+            self.config.api_base.path, self.config.token, file_path
+        );
+        let (mut connection, response) = self
+            .send(
+                || {
+                    let req = Request::builder()
+                        .method("GET")
+                        .uri(&uri)
+                        .header("host", self.config.api_base.authority())
+                        .header("connection", "keep-alive")
+                        .body(Vec::new())
+                        .unwrap();
+                    HttpMsg::Request(req)
+                },
+                //a GET request has no body
+                &Body::bytes(Vec::new(), String::new()),
+                &mut false,
+            )
+            .await?;
+        if !response.get_response().status().is_success() {
+            let response = self.finish(connection, response).await?;
+            return Err(decode::<bool>(&response)
+                .err()
+                .unwrap_or_else(|| status_error(&response)));
+        }
+        //the connection is dropped with the unread body
+        let size = response.content_length()?.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "download response has no content-length",
+            )
+        })?;
+        if size > self.config.max_download_size {
+            //the body is not read, so the connection can not be reused
+            return Err(Error::FileTooLarge {
+                size,
+                limit: self.config.max_download_size,
+            });
+        }
+        connection.read_body(size, writer).await?;
+        if response.keep_alive() {
+            self.checkin(connection);
+        }
+        Ok(size)
+    }
+    ///Looks the file up with getFile and streams its content into writer.
+    pub async fn download_file<W>(&self, file_id: &str, writer: &mut W) -> Result<File, Error>
+    where
+        W: AsyncWrite + std::marker::Unpin,
+    {
+        let method = telegram_methods::getFile {
+            file_id: String::from(file_id),
+        };
+        let file = self.call(&method).await?;
+        if let Some(size) = file.file_size {
+            self.check_size(size)?;
+        }
+        //telegram only returns a file_path for files it lets bots download
+        let file_path = file.file_path.as_ref().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, "getFile returned no file_path")
+        })?;
+        self.download_file_path(file_path, writer).await?;
+        Ok(file)
+    }
+    ///Like download_file, but writes the content to a new file at path.
+    /// The file is removed again if the download fails.
+    pub async fn download_file_to<P>(&self, file_id: &str, path: P) -> Result<File, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let mut output = async_std::fs::File::create(path).await?;
+        let result = match self.download_file(file_id, &mut output).await {
+            Ok(file) => output.flush().await.map(|_| file).map_err(Error::from),
+            Err(err) => Err(err),
+        };
+        if result.is_err() {
+            drop(output);
+            let _ = async_std::fs::remove_file(path).await;
+        }
+        result
+    }
+    ///Downloads a photo, document, voice message, sticker, ... into writer.
+    /// Files that are known to be too large are refused without a request.
+    pub async fn download<D, W>(&self, item: &D, writer: &mut W) -> Result<File, Error>
+    where
+        D: Downloadable,
+        W: AsyncWrite + std::marker::Unpin,
+    {
+        if let Some(size) = item.file_size() {
+            self.check_size(size)?;
+        }
+        self.download_file(item.file_id(), writer).await
+    }
+    ///Downloads a photo, document, voice message, sticker, ... to a new file at path.
+    pub async fn download_to<D, P>(&self, item: &D, path: P) -> Result<File, Error>
+    where
+        D: Downloadable,
+        P: AsRef<Path>,
+    {
+        if let Some(size) = item.file_size() {
+            self.check_size(size)?;
+        }
+        self.download_file_to(item.file_id(), path).await
+    }
+    fn check_size(&self, size: i64) -> Result<(), Error> {
+        let size = size.max(0) as u64;
+        if size > self.config.max_download_size {
+            return Err(Error::FileTooLarge {
+                size,
+                limit: self.config.max_download_size,
+            });
+        }
+        Ok(())
     }
 
-    pub async fn register_web_hook(&self, receiver: &TelegramReceiver) -> std::io::Result<()> {
-        let method = telegram_methods::setWebhookBuilder::default()
-            .url(receiver.get_webhook_uri())
-            .build()
-            .unwrap();
-        let http_msg = self.call(&method).await?;
-        assert_eq!(http_msg.get_response().status().as_u16(), 200);
+    pub async fn register_web_hook(&self, receiver: &TelegramReceiver) -> Result<(), Error> {
+        let mut builder = telegram_methods::setWebhookBuilder::default();
+        builder.url(receiver.get_webhook_uri());
+        if let Some(secret_token) = receiver.get_secret_token() {
+            builder.secret_token(secret_token);
+        }
+        let method = builder.build().unwrap();
+        self.call(&method).await?;
         Ok(())
     }
 }
+
+///Deserializes the result of a method call from the response envelope
+fn decode<T>(http_msg: &HttpMsg) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let status = http_msg.get_response().status();
+    match serde_json::from_slice::<Response<T>>(http_msg.get_body()) {
+        Ok(response) => response.into_result(),
+        Err(err) if status.is_success() => Err(Error::Json(err)),
+        //not even an error envelope, fall back to the http status
+        Err(_) => Err(status_error(http_msg)),
+    }
+}
+
+fn status_error(http_msg: &HttpMsg) -> Error {
+    let status = http_msg.get_response().status();
+    Error::Api {
+        error_code: i64::from(status.as_u16()),
+        description: String::from(status.canonical_reason().unwrap_or("")),
+        retry_after: None,
+        migrate_to_chat_id: None,
+    }
+}
+
+///Errors that mean the server closed an idle keep-alive connection
+fn is_closed(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::UnexpectedEof
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::BrokenPipe
+    )
+}
+
+///A local server for tests, the sender config returned by bind points at it
+#[cfg(test)]
+pub(crate) mod mock_server {
+    use super::Config;
+    use crate::http_stream::{HttpMsg, HttpStream};
+    use async_std::net::{TcpListener, TcpStream};
+
+    ///Listens on a free local port, the config uses the token "123:abc"
+    pub(crate) fn bind() -> (TcpListener, Config) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let config = Config::new(String::from("123:abc"))
+            .api_base(&format!("http://127.0.0.1:{}", port))
+            .unwrap();
+        (TcpListener::from(listener), config)
+    }
+    pub(crate) async fn accept(listener: &TcpListener) -> HttpStream<TcpStream> {
+        let (tcp_stream, _) = listener.accept().await.unwrap();
+        HttpStream::new(tcp_stream)
+    }
+    ///Reads a body sent with chunked transfer-encoding, after the head was read with read_head
+    pub(crate) async fn read_chunked(http_stream: &mut HttpStream<TcpStream>) -> Vec<u8> {
+        let mut body = Vec::new();
+        loop {
+            let mut line = Vec::new();
+            while !line.ends_with(b"\r\n") {
+                http_stream.read_body(1, &mut line).await.unwrap();
+            }
+            let size = std::str::from_utf8(&line[..line.len() - 2]).unwrap();
+            let size = u64::from_str_radix(size, 16).unwrap();
+            let mut chunk = Vec::new();
+            http_stream.read_body(size + 2, &mut chunk).await.unwrap();
+            body.extend_from_slice(&chunk[..size as usize]);
+            if size == 0 {
+                return body;
+            }
+        }
+    }
+    ///Answers with status 200
+    pub(crate) async fn respond(http_stream: &mut HttpStream<TcpStream>, body: &str) {
+        respond_with(http_stream, 200, body).await
+    }
+    pub(crate) async fn respond_with(
+        http_stream: &mut HttpStream<TcpStream>,
+        status: u16,
+        body: &str,
+    ) {
+        let body = body.as_bytes().to_vec();
+        let response = http::Response::builder()
+            .status(status)
+            .header("content-length", body.len())
+            .body(body)
+            .unwrap();
+        http_stream
+            .write(HttpMsg::Response(response))
+            .await
+            .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock_server::{self, respond, respond_with};
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn error_envelope_becomes_api_error() {
+        let body = r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 7","parameters":{"retry_after":7}}"#;
+        let response: Response<bool> = serde_json::from_str(body).unwrap();
+        match response.into_result() {
+            Err(Error::Api {
+                error_code: 429,
+                retry_after: Some(7),
+                migrate_to_chat_id: None,
+                ..
+            }) => {}
+            _ => panic!("expected a flood control error"),
+        }
+    }
+
+    #[test]
+    fn api_base_can_point_to_a_local_server() {
+        block_on(async {
+            let (listener, config) = mock_server::bind();
+            let port = listener.local_addr().unwrap().port();
+            let config = config
+                .api_base(&format!("http://127.0.0.1:{}/mock/", port))
+                .unwrap();
+            let sender = TelegramSender::new(config);
+            let server = async {
+                //both calls have to arrive on the same pooled connection
+                let mut http_stream = mock_server::accept(&listener).await;
+                for _ in 0..2 {
+                    let request = http_stream.read().await.unwrap();
+                    let request = request.get_request();
+                    assert_eq!(request.uri(), "/mock/bot123:abc/deleteWebhook");
+                    assert_eq!(request.headers()["host"], format!("127.0.0.1:{}", port));
+                    respond(&mut http_stream, r#"{"ok":true,"result":true}"#).await;
+                }
+            };
+            let client = async {
+                let method = crate::telegram_methods::deleteWebhook {};
+                assert!(sender.call(&method).await.unwrap());
+                assert!(sender.call(&method).await.unwrap());
+            };
+            futures::join!(server, client);
+        });
+    }
+
+    #[test]
+    fn readers_are_uploaded_chunked() {
+        use crate::helpers::Or;
+        use crate::input_file::InputFile;
+
+        block_on(async {
+            let (listener, config) = mock_server::bind();
+            let sender = TelegramSender::new(config);
+            let server = async {
+                let mut http_stream = mock_server::accept(&listener).await;
+                let request = http_stream.read_head().await.unwrap();
+                let headers = request.get_headers();
+                assert_eq!(headers["transfer-encoding"], "chunked");
+                assert!(headers.get("content-length").is_none());
+                let body = mock_server::read_chunked(&mut http_stream).await;
+                let body = String::from_utf8(body).unwrap();
+                assert!(body.contains("filename=\"log.txt\"\r\nContent-Type: application/octet-stream\r\n\r\nline 1\nline 2\r\n"));
+                let message = r#"{"ok":true,"result":{"message_id":1,"date":0,"chat":{"id":42,"type":"private"}}}"#;
+                respond(&mut http_stream, message).await;
+            };
+            let client = async {
+                let method = crate::telegram_methods::sendDocumentBuilder::default()
+                    .chat_id(42)
+                    .document(Or::A(InputFile::reader("log.txt", &b"line 1\nline 2"[..])))
+                    .build()
+                    .unwrap();
+                sender.call(&method).await.unwrap();
+            };
+            futures::join!(server, client);
+        });
+    }
+
+    #[test]
+    fn me_is_requested_once() {
+        block_on(async {
+            let (listener, config) = mock_server::bind();
+            let sender = TelegramSender::new(config);
+            let server = async {
+                let mut http_stream = mock_server::accept(&listener).await;
+                let request = http_stream.read().await.unwrap();
+                assert_eq!(request.get_request().uri(), "/bot123:abc/getMe");
+                let me = r#"{"ok":true,"result":{"id":123,"is_bot":true,"first_name":"Bot","username":"my_bot"}}"#;
+                respond(&mut http_stream, me).await;
+            };
+            let client = async {
+                assert_eq!(sender.me().await.unwrap().id, 123);
+                //the server answers only once, the second call has to use the cache
+                let me = sender.me().await.unwrap();
+                assert_eq!(me.username.as_deref(), Some("my_bot"));
+            };
+            futures::join!(server, client);
+        });
+    }
+
+    fn send_message() -> telegram_methods::sendMessage {
+        telegram_methods::sendMessageBuilder::default()
+            .chat_id(5)
+            .text(String::from("hi"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn message_is_not_sent_again_after_the_connection_closed() {
+        use async_std::future::timeout;
+
+        block_on(async {
+            let (listener, config) = mock_server::bind();
+            let policy = RetryPolicy::new().initial_delay(Duration::from_millis(1));
+            let config = config.no_rate_limits().retry_policy(policy);
+            let sender = TelegramSender::new(config);
+            let server = async {
+                let mut http_stream = mock_server::accept(&listener).await;
+                http_stream.read().await.unwrap();
+                respond(&mut http_stream, r#"{"ok":true,"result":true}"#).await;
+                //the message arrives on the pooled connection, which is closed without an answer
+                let request = http_stream.read().await.unwrap();
+                assert_eq!(request.get_request().uri(), "/bot123:abc/sendMessage");
+                drop(http_stream);
+                let again = timeout(Duration::from_millis(300), listener.accept()).await;
+                assert!(again.is_err(), "sendMessage was sent again");
+            };
+            let client = async {
+                let method = telegram_methods::deleteWebhook {};
+                assert!(sender.call(&method).await.unwrap());
+                match sender.call(&send_message()).await {
+                    Err(Error::IO(_)) => {}
+                    _ => panic!("expected a connection error"),
+                }
+            };
+            futures::join!(server, client);
+        });
+    }
+
+    #[test]
+    fn connection_closed_while_idle_is_replaced() {
+        use futures::channel::oneshot;
+
+        block_on(async {
+            let (listener, config) = mock_server::bind();
+            let config = config.no_rate_limits().retry_policy(RetryPolicy::none());
+            let sender = TelegramSender::new(config);
+            let (closed_send, closed_recv) = oneshot::channel();
+            let server = async {
+                let mut http_stream = mock_server::accept(&listener).await;
+                http_stream.read().await.unwrap();
+                respond(&mut http_stream, r#"{"ok":true,"result":true}"#).await;
+                //the server drops the idle keep-alive connection
+                drop(http_stream);
+                closed_send.send(()).unwrap();
+                let mut http_stream = mock_server::accept(&listener).await;
+                let request = http_stream.read().await.unwrap();
+                assert_eq!(request.get_request().uri(), "/bot123:abc/sendMessage");
+                let message = r#"{"ok":true,"result":{"message_id":1,"date":0,"chat":{"id":5,"type":"private"},"text":"hi"}}"#;
+                respond(&mut http_stream, message).await;
+            };
+            let client = async {
+                let method = telegram_methods::deleteWebhook {};
+                assert!(sender.call(&method).await.unwrap());
+                closed_recv.await.unwrap();
+                sender.call(&send_message()).await.unwrap();
+            };
+            futures::join!(server, client);
+        });
+    }
+
+    #[test]
+    fn server_errors_are_retried() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        block_on(async {
+            let (listener, config) = mock_server::bind();
+            let retries = Arc::new(AtomicUsize::new(0));
+            let counter = retries.clone();
+            let policy = RetryPolicy::new()
+                .initial_delay(Duration::from_millis(1))
+                .on_retry(move |retry| {
+                    assert_eq!(retry.method_name, "deleteWebhook");
+                    counter.fetch_add(1, Ordering::SeqCst);
+                });
+            let sender = TelegramSender::new(config.retry_policy(policy));
+            let server = async {
+                let mut http_stream = mock_server::accept(&listener).await;
+                http_stream.read().await.unwrap();
+                respond_with(&mut http_stream, 502, "").await;
+                http_stream.read().await.unwrap();
+                respond(&mut http_stream, r#"{"ok":true,"result":true}"#).await;
+            };
+            let client = async {
+                let method = telegram_methods::deleteWebhook {};
+                assert!(sender.call(&method).await.unwrap());
+            };
+            futures::join!(server, client);
+            assert_eq!(retries.load(Ordering::SeqCst), 1);
+        });
+    }
+
+    #[test]
+    fn flood_error_waits_once_without_rate_limiter() {
+        block_on(async {
+            let (listener, config) = mock_server::bind();
+            let sender = TelegramSender::new(config.no_rate_limits());
+            let server = async {
+                let mut http_stream = mock_server::accept(&listener).await;
+                http_stream.read().await.unwrap();
+                let flood = r#"{"ok":false,"error_code":429,"description":"Too Many Requests: retry after 1","parameters":{"retry_after":1}}"#;
+                respond_with(&mut http_stream, 429, flood).await;
+                http_stream.read().await.unwrap();
+                let message = r#"{"ok":true,"result":{"message_id":1,"date":0,"chat":{"id":5,"type":"private"},"text":"hi"}}"#;
+                respond(&mut http_stream, message).await;
+            };
+            let client = async {
+                let start = Instant::now();
+                sender.call(&send_message()).await.unwrap();
+                let elapsed = start.elapsed();
+                assert!(elapsed >= Duration::from_secs(1) && elapsed < Duration::from_secs(2));
+            };
+            futures::join!(server, client);
+        });
+    }
+
+    #[test]
+    fn channel_username_is_resolved_once() {
+        block_on(async {
+            let (listener, config) = mock_server::bind();
+            let sender = TelegramSender::new(config);
+            let server = async {
+                let mut http_stream = mock_server::accept(&listener).await;
+                let chat = r#"{"ok":true,"result":{"id":-100,"type":"channel"}}"#;
+                let message = r#"{"ok":true,"result":{"message_id":1,"date":0,"chat":{"id":-100,"type":"channel"}}}"#;
+                let expected = [
+                    ("/bot123:abc/getChat", chat),
+                    ("/bot123:abc/sendMessage", message),
+                    ("/bot123:abc/sendMessage", message),
+                ];
+                for (uri, answer) in expected.iter() {
+                    let request = http_stream.read().await.unwrap();
+                    assert_eq!(request.get_request().uri(), uri);
+                    respond(&mut http_stream, answer).await;
+                }
+            };
+            let client = async {
+                for _ in 0..2 {
+                    let method = telegram_methods::sendMessageBuilder::default()
+                        .chat_id("@channel")
+                        .text(String::from("hi"))
+                        .build()
+                        .unwrap();
+                    sender.call(&method).await.unwrap();
+                }
+            };
+            futures::join!(server, client);
+            let limiter = sender.limiter.as_ref().unwrap();
+            assert_eq!(limiter.resolved("@channel"), Some(-100));
+        });
+    }
+
+    #[test]
+    fn download_streams_the_file() {
+        block_on(async {
+            let (listener, config) = mock_server::bind();
+            let sender = TelegramSender::new(config);
+            let server = async {
+                let mut http_stream = mock_server::accept(&listener).await;
+                let request = http_stream.read().await.unwrap();
+                assert_eq!(request.get_request().uri(), "/bot123:abc/getFile");
+                let file = r#"{"ok":true,"result":{"file_id":"f","file_size":5,"file_path":"photos/1.jpg"}}"#;
+                respond(&mut http_stream, file).await;
+                let request = http_stream.read().await.unwrap();
+                assert_eq!(request.get_request().uri(), "/file/bot123:abc/photos/1.jpg");
+                respond(&mut http_stream, "hello").await;
+            };
+            let client = async {
+                let mut content = Vec::new();
+                let file = sender.download_file("f", &mut content).await.unwrap();
+                assert_eq!(file.file_path.as_deref(), Some("photos/1.jpg"));
+                assert_eq!(content, b"hello");
+            };
+            futures::join!(server, client);
+        });
+    }
+
+    #[test]
+    fn download_refuses_large_files_and_unknown_sizes() {
+        block_on(async {
+            let (listener, config) = mock_server::bind();
+            let sender = TelegramSender::new(config.max_download_size(4));
+            let server = async {
+                //every download needs a new connection, the unread body makes the old one unusable
+                let mut http_stream = mock_server::accept(&listener).await;
+                http_stream.read().await.unwrap();
+                respond(&mut http_stream, "hello").await;
+                let mut http_stream = mock_server::accept(&listener).await;
+                http_stream.read().await.unwrap();
+                let response = http::Response::builder()
+                    .header("transfer-encoding", "chunked")
+                    .body(b"2\r\nhi\r\n0\r\n\r\n".to_vec())
+                    .unwrap();
+                http_stream
+                    .write(HttpMsg::Response(response))
+                    .await
+                    .unwrap();
+            };
+            let client = async {
+                let mut content = Vec::new();
+                match sender.download_file_path("big", &mut content).await {
+                    Err(Error::FileTooLarge { size: 5, limit: 4 }) => {}
+                    _ => panic!("expected FileTooLarge"),
+                }
+                match sender.download_file_path("chunked", &mut content).await {
+                    Err(Error::IO(err)) => assert_eq!(err.kind(), io::ErrorKind::InvalidData),
+                    _ => panic!("expected an error for the missing content-length"),
+                }
+                assert!(content.is_empty());
+                assert!(sender.pool.lock().unwrap().is_empty());
+            };
+            futures::join!(server, client);
+        });
+    }
+}
@@ -83,15 +83,12 @@ def make_structs(lines):
 def call_make_structs():
     print("use serde::{Serialize,Deserialize};")
     print("use crate::helpers::Or;")
+    print("pub use crate::input_file::InputFile;")
     print("type Integer=i64;")
     print("type Boolean=bool;")
     print("type True=bool;")
     print("type Float=f64;")
 
-    print("//unsupported types")
-    print("type Game = ();")
-    print("type CallbackGame = ();")
-    print("type InputFile = ();")
     make_structs([
     "WebhookInfo",
     "",
@@ -1177,8 +1174,6 @@ def call_make_methods():
     print("type True=bool;")
     print("type Float=f64;")
 
-    print("//unsupported types")
-    print("type InputFile = ();")
     print("///Marker Trait for TelegramMethod structs")
     print("pub trait TelegramMethod {const method_name: &'static str;}")
     make_methods([
@@ -1210,17 +1205,6 @@ def call_make_methods():
     "",
     "Use this method to get current webhook status. Requires no parameters. On success, returns a WebhookInfo object. If the bot is using getUpdates, will return an object with the url field empty.",
     "Parameter 	Type 	Required 	Description",
-    "WebhookInfo",
-    "",
-    "Contains information about the current status of a webhook.",
-    "Field 	Type 	Description",
-    "url 	String 	Webhook URL, may be empty if webhook is not set up",
-    "has_custom_certificate 	Boolean 	True, if a custom certificate was provided for webhook certificate checks",
-    "pending_update_count 	Integer 	Number of updates awaiting delivery",
-    "last_error_date 	Integer 	Optional. Unix time for the most recent error that happened when trying to deliver an update via webhook",
-    "last_error_message 	String 	Optional. Error message in human-readable format for the most recent error that happened when trying to deliver an update via webhook",
-    "max_connections 	Integer 	Optional. Maximum allowed number of simultaneous HTTPS connections to the webhook for update delivery",
-    "allowed_updates 	Array of String 	Optional. A list of update types the bot is subscribed to. Defaults to all update types",
     "getMe",
     "",
     "A simple method for testing your bot's auth token. Requires no parameters. Returns basic information about the bot in form of a User object.",
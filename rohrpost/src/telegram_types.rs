@@ -1,13 +1,102 @@
-use crate::helpers::Or;
+pub use crate::chat_id::ChatId;
+use crate::helpers::{string_enum, Or};
+pub use crate::input_file::InputFile;
 use serde::{Deserialize, Serialize};
 type Integer = i64;
 type Boolean = bool;
 type True = bool;
 type Float = f64;
-//unsupported types
-type Game = ();
-type CallbackGame = ();
-type InputFile = ();
+
+string_enum! {
+    ///Type of a Chat
+    pub enum ChatType {
+        Private = "private",
+        Group = "group",
+        Supergroup = "supergroup",
+        Channel = "channel",
+    }
+}
+string_enum! {
+    ///Status of a ChatMember
+    pub enum ChatMemberStatus {
+        Creator = "creator",
+        Administrator = "administrator",
+        Member = "member",
+        Restricted = "restricted",
+        Left = "left",
+        Kicked = "kicked",
+    }
+}
+string_enum! {
+    ///Type of a MessageEntity
+    pub enum MessageEntityType {
+        Mention = "mention",
+        Hashtag = "hashtag",
+        Cashtag = "cashtag",
+        BotCommand = "bot_command",
+        Url = "url",
+        Email = "email",
+        PhoneNumber = "phone_number",
+        Bold = "bold",
+        Italic = "italic",
+        Underline = "underline",
+        Strikethrough = "strikethrough",
+        Code = "code",
+        Pre = "pre",
+        TextLink = "text_link",
+        TextMention = "text_mention",
+    }
+}
+string_enum! {
+    ///Formatting of a text or caption
+    pub enum ParseMode {
+        Markdown = "Markdown",
+        MarkdownV2 = "MarkdownV2",
+        Html = "HTML",
+    }
+}
+string_enum! {
+    ///The part of the face where a mask should be placed
+    pub enum MaskPoint {
+        Forehead = "forehead",
+        Eyes = "eyes",
+        Mouth = "mouth",
+        Chin = "chin",
+    }
+}
+string_enum! {
+    ///Type of a Telegram Passport element
+    pub enum PassportElementType {
+        PersonalDetails = "personal_details",
+        Passport = "passport",
+        DriverLicense = "driver_license",
+        IdentityCard = "identity_card",
+        InternalPassport = "internal_passport",
+        Address = "address",
+        UtilityBill = "utility_bill",
+        BankStatement = "bank_statement",
+        RentalAgreement = "rental_agreement",
+        PassportRegistration = "passport_registration",
+        TemporaryRegistration = "temporary_registration",
+        PhoneNumber = "phone_number",
+        Email = "email",
+    }
+}
+string_enum! {
+    ///Action shown to the user with sendChatAction
+    pub enum ChatAction {
+        Typing = "typing",
+        UploadPhoto = "upload_photo",
+        RecordVideo = "record_video",
+        UploadVideo = "upload_video",
+        RecordAudio = "record_audio",
+        UploadAudio = "upload_audio",
+        UploadDocument = "upload_document",
+        FindLocation = "find_location",
+        RecordVideoNote = "record_video_note",
+        UploadVideoNote = "upload_video_note",
+    }
+}
 ///
 ///Contains information about the current status of a webhook.
 #[derive(Serialize, Deserialize, Builder, Clone)]
@@ -55,13 +144,45 @@ pub struct InlineQuery {
     pub offset: String,
 }
 ///
+///This object represents one result of an inline query. Each variant is serialized with its type.
+/// The cached variants share the type of their counterpart, telegram tells them apart by the file id field.
+#[derive(Serialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InlineQueryResult {
+    Article(InlineQueryResultArticle),
+    Photo(InlineQueryResultPhoto),
+    Gif(InlineQueryResultGif),
+    Mpeg4Gif(InlineQueryResultMpeg4Gif),
+    Video(InlineQueryResultVideo),
+    Audio(InlineQueryResultAudio),
+    Voice(InlineQueryResultVoice),
+    Document(InlineQueryResultDocument),
+    Location(InlineQueryResultLocation),
+    Venue(InlineQueryResultVenue),
+    Contact(InlineQueryResultContact),
+    Game(InlineQueryResultGame),
+    #[serde(rename = "photo")]
+    CachedPhoto(InlineQueryResultCachedPhoto),
+    #[serde(rename = "gif")]
+    CachedGif(InlineQueryResultCachedGif),
+    #[serde(rename = "mpeg4_gif")]
+    CachedMpeg4Gif(InlineQueryResultCachedMpeg4Gif),
+    #[serde(rename = "sticker")]
+    CachedSticker(InlineQueryResultCachedSticker),
+    #[serde(rename = "document")]
+    CachedDocument(InlineQueryResultCachedDocument),
+    #[serde(rename = "video")]
+    CachedVideo(InlineQueryResultCachedVideo),
+    #[serde(rename = "voice")]
+    CachedVoice(InlineQueryResultCachedVoice),
+    #[serde(rename = "audio")]
+    CachedAudio(InlineQueryResultCachedAudio),
+}
+///
 ///Represents a link to an article or web page.
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultArticle {
-    ///Type of the result, must be article
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 Bytes
     pub id: String,
     ///Title of the result
@@ -102,9 +223,6 @@ pub struct InlineQueryResultArticle {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultPhoto {
-    ///Type of the result, must be photo
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid URL of the photo. Photo must be in jpeg format. Photo size must not exceed 5MB
@@ -134,7 +252,7 @@ pub struct InlineQueryResultPhoto {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Inline keyboard attached to the message
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -149,9 +267,6 @@ pub struct InlineQueryResultPhoto {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultGif {
-    ///Type of the result, must be gif
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid URL for the GIF file. File size must not exceed 1MB
@@ -181,7 +296,7 @@ pub struct InlineQueryResultGif {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Inline keyboard attached to the message
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -196,9 +311,6 @@ pub struct InlineQueryResultGif {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultMpeg4Gif {
-    ///Type of the result, must be mpeg4_gif
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid URL for the MP4 file. File size must not exceed 1MB
@@ -228,7 +340,7 @@ pub struct InlineQueryResultMpeg4Gif {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Inline keyboard attached to the message
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -246,9 +358,6 @@ pub struct InlineQueryResultMpeg4Gif {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultVideo {
-    ///Type of the result, must be video
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid URL for the embedded video player or video file
@@ -266,7 +375,7 @@ pub struct InlineQueryResultVideo {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Video width
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -297,9 +406,6 @@ pub struct InlineQueryResultVideo {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultAudio {
-    ///Type of the result, must be audio
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid URL for the audio file
@@ -313,7 +419,7 @@ pub struct InlineQueryResultAudio {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Performer
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -338,9 +444,6 @@ pub struct InlineQueryResultAudio {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultVoice {
-    ///Type of the result, must be voice
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid URL for the voice recording
@@ -354,7 +457,7 @@ pub struct InlineQueryResultVoice {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Recording duration in seconds
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -375,9 +478,6 @@ pub struct InlineQueryResultVoice {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultDocument {
-    ///Type of the result, must be document
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///Title for the result
@@ -389,7 +489,7 @@ pub struct InlineQueryResultDocument {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///A valid URL for the file
     pub document_url: String,
     ///Mime type of the content of the file, either “application/pdf” or “application/zip”
@@ -426,9 +526,6 @@ pub struct InlineQueryResultDocument {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultLocation {
-    ///Type of the result, must be location
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 Bytes
     pub id: String,
     ///Location latitude in degrees
@@ -469,9 +566,6 @@ pub struct InlineQueryResultLocation {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultVenue {
-    ///Type of the result, must be venue
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 Bytes
     pub id: String,
     ///Latitude of the venue location in degrees
@@ -518,9 +612,6 @@ pub struct InlineQueryResultVenue {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultContact {
-    ///Type of the result, must be contact
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 Bytes
     pub id: String,
     ///Contact's phone number
@@ -563,9 +654,6 @@ pub struct InlineQueryResultContact {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultGame {
-    ///Type of the result, must be game
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///Short name of the game
@@ -582,9 +670,6 @@ pub struct InlineQueryResultGame {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultCachedPhoto {
-    ///Type of the result, must be photo
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid file identifier of the photo
@@ -604,7 +689,7 @@ pub struct InlineQueryResultCachedPhoto {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Inline keyboard attached to the message
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -619,9 +704,6 @@ pub struct InlineQueryResultCachedPhoto {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultCachedGif {
-    ///Type of the result, must be gif
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid file identifier for the GIF file
@@ -637,7 +719,7 @@ pub struct InlineQueryResultCachedGif {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Inline keyboard attached to the message
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -652,9 +734,6 @@ pub struct InlineQueryResultCachedGif {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultCachedMpeg4Gif {
-    ///Type of the result, must be mpeg4_gif
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid file identifier for the MP4 file
@@ -670,7 +749,7 @@ pub struct InlineQueryResultCachedMpeg4Gif {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Inline keyboard attached to the message
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -685,9 +764,6 @@ pub struct InlineQueryResultCachedMpeg4Gif {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultCachedSticker {
-    ///Type of the result, must be sticker
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid file identifier of the sticker
@@ -708,9 +784,6 @@ pub struct InlineQueryResultCachedSticker {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultCachedDocument {
-    ///Type of the result, must be document
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///Title for the result
@@ -728,7 +801,7 @@ pub struct InlineQueryResultCachedDocument {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Inline keyboard attached to the message
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -745,9 +818,6 @@ pub struct InlineQueryResultCachedDocument {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultCachedVideo {
-    ///Type of the result, must be video
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid file identifier for the video file
@@ -765,7 +835,7 @@ pub struct InlineQueryResultCachedVideo {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Inline keyboard attached to the message
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -780,9 +850,6 @@ pub struct InlineQueryResultCachedVideo {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultCachedVoice {
-    ///Type of the result, must be voice
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid file identifier for the voice message
@@ -796,7 +863,7 @@ pub struct InlineQueryResultCachedVoice {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Inline keyboard attached to the message
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -813,9 +880,6 @@ pub struct InlineQueryResultCachedVoice {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InlineQueryResultCachedAudio {
-    ///Type of the result, must be audio
-    #[serde(rename = "type")]
-    pub type_: String,
     ///Unique identifier for this result, 1-64 bytes
     pub id: String,
     ///A valid file identifier for the audio file
@@ -827,7 +891,7 @@ pub struct InlineQueryResultCachedAudio {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Inline keyboard attached to the message
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -841,24 +905,47 @@ pub struct InlineQueryResultCachedAudio {
 }
 ///
 ///This object represents the content of a message to be sent as a result of an inline query. Telegram clients currently support the following 4 types:
-///
-///    InputTextMessageContent
-///    InputLocationMessageContent
-///    InputVenueMessageContent
-///    InputContactMessageContent
-///
-///InputTextMessageContent
+///InputTextMessageContent, InputLocationMessageContent, InputVenueMessageContent and InputContactMessageContent.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum InputMessageContent {
+    //a venue is a superset of a location, so it has to be tried first
+    Venue(InputVenueMessageContent),
+    Location(InputLocationMessageContent),
+    Contact(InputContactMessageContent),
+    Text(InputTextMessageContent),
+}
+impl From<InputTextMessageContent> for InputMessageContent {
+    fn from(content: InputTextMessageContent) -> Self {
+        InputMessageContent::Text(content)
+    }
+}
+impl From<InputLocationMessageContent> for InputMessageContent {
+    fn from(content: InputLocationMessageContent) -> Self {
+        InputMessageContent::Location(content)
+    }
+}
+impl From<InputVenueMessageContent> for InputMessageContent {
+    fn from(content: InputVenueMessageContent) -> Self {
+        InputMessageContent::Venue(content)
+    }
+}
+impl From<InputContactMessageContent> for InputMessageContent {
+    fn from(content: InputContactMessageContent) -> Self {
+        InputMessageContent::Contact(content)
+    }
+}
 ///
 ///Represents the content of a text message to be sent as the result of an inline query.
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
-pub struct InputMessageContent {
+pub struct InputTextMessageContent {
     ///Text of the message to be sent, 1-4096 characters
     pub message_text: String,
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in your bot's message.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Disables link previews for links in the sent message
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1022,7 +1109,7 @@ pub struct Chat {
     pub id: Integer,
     ///Type of chat, can be either “private”, “group”, “supergroup” or “channel”
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ChatType,
     ///Optional. Title, for supergroups, channels and group chats
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1263,7 +1350,7 @@ pub struct Message {
 pub struct MessageEntity {
     ///Type of the entity. Can be mention (@username), hashtag, cashtag, bot_command, url, email, phone_number, bold (bold text), italic (italic text), code (monowidth string), pre (monowidth block), text_link (for clickable text URLs), text_mention (for users without usernames)
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: MessageEntityType,
     ///Offset in UTF-16 code units to the start of the entity
     pub offset: Integer,
     ///Length of the entity in UTF-16 code units
@@ -1547,6 +1634,36 @@ pub struct File {
     pub file_path: Option<String>,
 }
 ///
+///Additional interface options for a message: an inline keyboard, a custom reply keyboard, instructions to remove the reply keyboard or to force a reply from the user.
+#[derive(Serialize, Clone)]
+#[serde(untagged)]
+pub enum ReplyMarkup {
+    InlineKeyboard(InlineKeyboardMarkup),
+    ReplyKeyboard(ReplyKeyboardMarkup),
+    ReplyKeyboardRemove(ReplyKeyboardRemove),
+    ForceReply(ForceReply),
+}
+impl From<InlineKeyboardMarkup> for ReplyMarkup {
+    fn from(markup: InlineKeyboardMarkup) -> Self {
+        ReplyMarkup::InlineKeyboard(markup)
+    }
+}
+impl From<ReplyKeyboardMarkup> for ReplyMarkup {
+    fn from(markup: ReplyKeyboardMarkup) -> Self {
+        ReplyMarkup::ReplyKeyboard(markup)
+    }
+}
+impl From<ReplyKeyboardRemove> for ReplyMarkup {
+    fn from(markup: ReplyKeyboardRemove) -> Self {
+        ReplyMarkup::ReplyKeyboardRemove(markup)
+    }
+}
+impl From<ForceReply> for ReplyMarkup {
+    fn from(markup: ForceReply) -> Self {
+        ReplyMarkup::ForceReply(markup)
+    }
+}
+///
 ///This object represents a custom keyboard with reply options (see Introduction to bots for details and examples).
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
@@ -1751,7 +1868,7 @@ pub struct ChatMember {
     ///Information about the user
     pub user: User,
     ///The member's status in the chat. Can be “creator”, “administrator”, “member”, “restricted”, “left” or “kicked”
-    pub status: String,
+    pub status: ChatMemberStatus,
     ///Optional. Restricted and kicked only. Date when restrictions will be lifted for this user; unix time
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1870,15 +1987,48 @@ pub struct ResponseParameters {
     pub retry_after: Option<Integer>,
 }
 ///
+///This object represents the content of a media message to be sent.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum InputMedia {
+    Photo(InputMediaPhoto),
+    Video(InputMediaVideo),
+    Animation(InputMediaAnimation),
+    Audio(InputMediaAudio),
+    Document(InputMediaDocument),
+}
+impl From<InputMediaPhoto> for InputMedia {
+    fn from(media: InputMediaPhoto) -> Self {
+        InputMedia::Photo(media)
+    }
+}
+impl From<InputMediaVideo> for InputMedia {
+    fn from(media: InputMediaVideo) -> Self {
+        InputMedia::Video(media)
+    }
+}
+impl From<InputMediaAnimation> for InputMedia {
+    fn from(media: InputMediaAnimation) -> Self {
+        InputMedia::Animation(media)
+    }
+}
+impl From<InputMediaAudio> for InputMedia {
+    fn from(media: InputMediaAudio) -> Self {
+        InputMedia::Audio(media)
+    }
+}
+impl From<InputMediaDocument> for InputMedia {
+    fn from(media: InputMediaDocument) -> Self {
+        InputMedia::Document(media)
+    }
+}
+///
 ///Represents a photo to be sent.
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InputMediaPhoto {
-    ///Type of the result, must be photo
-    #[serde(rename = "type")]
-    pub type_: String,
     ///File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More info on Sending Files »
-    pub media: String,
+    pub media: Or<InputFile, String>,
     ///Optional. Caption of the photo to be sent, 0-1024 characters
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1886,18 +2036,15 @@ pub struct InputMediaPhoto {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
 }
 ///
 ///Represents a video to be sent.
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InputMediaVideo {
-    ///Type of the result, must be video
-    #[serde(rename = "type")]
-    pub type_: String,
     ///File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More info on Sending Files »
-    pub media: String,
+    pub media: Or<InputFile, String>,
     ///Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail‘s width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can’t be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More info on Sending Files »
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1909,7 +2056,7 @@ pub struct InputMediaVideo {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Video width
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1932,11 +2079,8 @@ pub struct InputMediaVideo {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InputMediaAnimation {
-    ///Type of the result, must be animation
-    #[serde(rename = "type")]
-    pub type_: String,
     ///File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More info on Sending Files »
-    pub media: String,
+    pub media: Or<InputFile, String>,
     ///Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail‘s width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can’t be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More info on Sending Files »
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1948,7 +2092,7 @@ pub struct InputMediaAnimation {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Animation width
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1967,11 +2111,8 @@ pub struct InputMediaAnimation {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InputMediaAudio {
-    ///Type of the result, must be audio
-    #[serde(rename = "type")]
-    pub type_: String,
     ///File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More info on Sending Files »
-    pub media: String,
+    pub media: Or<InputFile, String>,
     ///Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail‘s width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can’t be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More info on Sending Files »
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1983,7 +2124,7 @@ pub struct InputMediaAudio {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
     ///Optional. Duration of the audio in seconds
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2002,11 +2143,8 @@ pub struct InputMediaAudio {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct InputMediaDocument {
-    ///Type of the result, must be document
-    #[serde(rename = "type")]
-    pub type_: String,
     ///File to send. Pass a file_id to send a file that exists on the Telegram servers (recommended), pass an HTTP URL for Telegram to get a file from the Internet, or pass “attach://<file_attach_name>” to upload a new one using multipart/form-data under <file_attach_name> name. More info on Sending Files »
-    pub media: String,
+    pub media: Or<InputFile, String>,
     ///Optional. Thumbnail of the file sent; can be ignored if thumbnail generation for the file is supported server-side. The thumbnail should be in JPEG format and less than 200 kB in size. A thumbnail‘s width and height should not exceed 320. Ignored if the file is not uploaded using multipart/form-data. Thumbnails can’t be reused and can be only uploaded as a new file, so you can pass “attach://<file_attach_name>” if the thumbnail was uploaded using multipart/form-data under <file_attach_name>. More info on Sending Files »
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2018,7 +2156,7 @@ pub struct InputMediaDocument {
     ///Optional. Send Markdown or HTML, if you want Telegram apps to show bold, italic, fixed-width text or inline URLs in the media caption.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parse_mode: Option<String>,
+    pub parse_mode: Option<ParseMode>,
 }
 ///
 ///This object represents a sticker.
@@ -2076,7 +2214,7 @@ pub struct StickerSet {
 #[builder(setter(strip_option))]
 pub struct MaskPosition {
     ///The part of the face relative to which the mask should be placed. One of “forehead”, “eyes”, “mouth”, or “chin”.
-    pub point: String,
+    pub point: MaskPoint,
     ///Shift by X-axis measured in widths of the mask scaled to the face size, from left to right. For example, choosing -1.0 will place mask just to the left of the default mask position.
     pub x_shift: Float,
     ///Shift by Y-axis measured in heights of the mask scaled to the face size, from top to bottom. For example, 1.0 will place the mask just below the default mask position.
@@ -2253,7 +2391,7 @@ pub struct PassportFile {
 pub struct EncryptedPassportElement {
     ///Element type. One of “personal_details”, “passport”, “driver_license”, “identity_card”, “internal_passport”, “address”, “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”, “phone_number”, “email”.
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: PassportElementType,
     ///Optional. Base64-encoded encrypted Telegram Passport element data provided by the user, available for “personal_details”, “passport”, “driver_license”, “identity_card”, “internal_passport” and “address” types. Can be decrypted and verified using the accompanying EncryptedCredentials.
     #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2302,12 +2440,26 @@ pub struct EncryptedCredentials {
     pub secret: String,
 }
 ///
+///This object represents an error in the Telegram Passport element which was submitted that should be resolved by the user.
+#[derive(Serialize, Clone)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum PassportElementError {
+    #[serde(rename = "data")]
+    DataField(PassportElementErrorDataField),
+    FrontSide(PassportElementErrorFrontSide),
+    ReverseSide(PassportElementErrorReverseSide),
+    Selfie(PassportElementErrorSelfie),
+    File(PassportElementErrorFile),
+    Files(PassportElementErrorFiles),
+    TranslationFile(PassportElementErrorTranslationFile),
+    TranslationFiles(PassportElementErrorTranslationFiles),
+    Unspecified(PassportElementErrorUnspecified),
+}
+///
 ///Represents an issue in one of the data fields that was provided by the user. The error is considered resolved when the field's value changes.
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct PassportElementErrorDataField {
-    ///Error source, must be data
-    pub source: String,
     ///The section of the user's Telegram Passport which has the error, one of “personal_details”, “passport”, “driver_license”, “identity_card”, “internal_passport”, “address”
     #[serde(rename = "type")]
     pub type_: String,
@@ -2323,8 +2475,6 @@ pub struct PassportElementErrorDataField {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct PassportElementErrorFrontSide {
-    ///Error source, must be front_side
-    pub source: String,
     ///The section of the user's Telegram Passport which has the issue, one of “passport”, “driver_license”, “identity_card”, “internal_passport”
     #[serde(rename = "type")]
     pub type_: String,
@@ -2338,8 +2488,6 @@ pub struct PassportElementErrorFrontSide {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct PassportElementErrorReverseSide {
-    ///Error source, must be reverse_side
-    pub source: String,
     ///The section of the user's Telegram Passport which has the issue, one of “driver_license”, “identity_card”
     #[serde(rename = "type")]
     pub type_: String,
@@ -2353,8 +2501,6 @@ pub struct PassportElementErrorReverseSide {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct PassportElementErrorSelfie {
-    ///Error source, must be selfie
-    pub source: String,
     ///The section of the user's Telegram Passport which has the issue, one of “passport”, “driver_license”, “identity_card”, “internal_passport”
     #[serde(rename = "type")]
     pub type_: String,
@@ -2368,8 +2514,6 @@ pub struct PassportElementErrorSelfie {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct PassportElementErrorFile {
-    ///Error source, must be file
-    pub source: String,
     ///The section of the user's Telegram Passport which has the issue, one of “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
     #[serde(rename = "type")]
     pub type_: String,
@@ -2383,8 +2527,6 @@ pub struct PassportElementErrorFile {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct PassportElementErrorFiles {
-    ///Error source, must be files
-    pub source: String,
     ///The section of the user's Telegram Passport which has the issue, one of “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
     #[serde(rename = "type")]
     pub type_: String,
@@ -2398,8 +2540,6 @@ pub struct PassportElementErrorFiles {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct PassportElementErrorTranslationFile {
-    ///Error source, must be translation_file
-    pub source: String,
     ///Type of element of the user's Telegram Passport which has the issue, one of “passport”, “driver_license”, “identity_card”, “internal_passport”, “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
     #[serde(rename = "type")]
     pub type_: String,
@@ -2413,8 +2553,6 @@ pub struct PassportElementErrorTranslationFile {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct PassportElementErrorTranslationFiles {
-    ///Error source, must be translation_files
-    pub source: String,
     ///Type of element of the user's Telegram Passport which has the issue, one of “passport”, “driver_license”, “identity_card”, “internal_passport”, “utility_bill”, “bank_statement”, “rental_agreement”, “passport_registration”, “temporary_registration”
     #[serde(rename = "type")]
     pub type_: String,
@@ -2428,8 +2566,6 @@ pub struct PassportElementErrorTranslationFiles {
 #[derive(Serialize, Deserialize, Builder, Clone)]
 #[builder(setter(strip_option))]
 pub struct PassportElementErrorUnspecified {
-    ///Error source, must be unspecified
-    pub source: String,
     ///Type of element of the user's Telegram Passport which has the issue
     #[serde(rename = "type")]
     pub type_: String,
@@ -2438,3 +2574,44 @@ pub struct PassportElementErrorUnspecified {
     ///Error message
     pub message: String,
 }
+///
+///This object represents a game. Use BotFather to create and edit games, their short names will act as unique identifiers.
+#[derive(Serialize, Deserialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct Game {
+    ///Title of the game
+    pub title: String,
+    ///Description of the game
+    pub description: String,
+    ///Photo that will be displayed in the game message in chats.
+    pub photo: Vec<PhotoSize>,
+    ///Optional. Brief description of the game or high scores included in the game message. Can be automatically edited to include current high scores for the game when the bot calls setGameScore, or manually edited using editMessageText. 0-4096 characters.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    ///Optional. Special entities that appear in text, such as usernames, URLs, bot commands, etc.
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_entities: Option<Vec<MessageEntity>>,
+    ///Optional. Animation that will be displayed in the game message in chats. Upload via BotFather
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animation: Option<Animation>,
+}
+///
+///A placeholder, currently holds no information. Use BotFather to set up your game.
+#[derive(Serialize, Deserialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct CallbackGame {}
+///
+///This object represents one row of the high scores table for a game.
+#[derive(Serialize, Deserialize, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct GameHighScore {
+    ///Position in high score table for the game
+    pub position: Integer,
+    ///User
+    pub user: User,
+    ///Score
+    pub score: Integer,
+}